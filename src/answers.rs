@@ -0,0 +1,207 @@
+use std::{collections::BTreeMap, fmt, fs, io, path::Path};
+
+use crate::{Error, Result};
+
+// known-good answers, stored in a small subset of TOML:
+//
+// [1]
+// part1 = "24000"
+// part2 = "45000"
+//
+// answers are always strings, since some of them (day 10) aren't numbers
+#[derive(Debug, Default, PartialEq)]
+pub struct Answers(BTreeMap<(u8, u8), String>);
+
+#[derive(Debug, PartialEq)]
+pub enum Check {
+    Pass,
+    Fail(String),
+    Missing,
+}
+
+impl Answers {
+    pub fn load(path: &Path) -> Result<Self> {
+        match fs::read_to_string(path) {
+            Ok(s) => Self::new(&s),
+            // nothing's been recorded yet
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(Self::default()),
+            Err(e) => Err(Error::input(&format!(
+                "could not read answers from {}: {}",
+                path.display(),
+                e
+            ))),
+        }
+    }
+
+    pub fn save(&self, path: &Path) -> Result<()> {
+        fs::write(path, self.to_string()).map_err(|e| {
+            Error::input(&format!(
+                "could not save answers to {}: {}",
+                path.display(),
+                e
+            ))
+        })
+    }
+
+    pub fn new(input: &str) -> Result<Self> {
+        let mut answers = BTreeMap::new();
+        let mut day = None;
+        for (line_index, line) in input.lines().enumerate() {
+            let parse_error = || Error::parse(line_index + 1, line);
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            if let Some(table) = line.strip_prefix('[').and_then(|l| l.strip_suffix(']')) {
+                day = Some(table.trim().parse::<u8>().map_err(|_| parse_error())?);
+                continue;
+            }
+            let (key, value) = line.split_once('=').ok_or_else(parse_error)?;
+            let part = match key.trim() {
+                "part1" => 1,
+                "part2" => 2,
+                _ => return Err(parse_error()),
+            };
+            let value = unescape(value.trim()).ok_or_else(parse_error)?;
+            answers.insert((day.ok_or_else(parse_error)?, part), value);
+        }
+        Ok(Self(answers))
+    }
+
+    pub fn get(&self, day: u8, part: u8) -> Option<&str> {
+        self.0.get(&(day, part)).map(|a| a.as_str())
+    }
+
+    pub fn set(&mut self, day: u8, part: u8, answer: &str) {
+        self.0.insert((day, part), answer.to_string());
+    }
+
+    pub fn check(&self, day: u8, part: u8, answer: &str) -> Check {
+        match self.get(day, part) {
+            Some(expected) if expected == answer => Check::Pass,
+            Some(expected) => Check::Fail(expected.to_string()),
+            None => Check::Missing,
+        }
+    }
+}
+
+impl fmt::Display for Answers {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let mut previous_day = None;
+        for ((day, part), answer) in &self.0 {
+            if previous_day != Some(day) {
+                if previous_day.is_some() {
+                    writeln!(f)?;
+                }
+                writeln!(f, "[{}]", day)?;
+                previous_day = Some(day);
+            }
+            writeln!(f, "part{} = {}", part, escape(answer))?;
+        }
+        Ok(())
+    }
+}
+
+pub fn escape(input: &str) -> String {
+    let mut s = "\"".to_string();
+    for c in input.chars() {
+        match c {
+            '\n' => s.push_str("\\n"),
+            '"' => s.push_str("\\\""),
+            '\\' => s.push_str("\\\\"),
+            c => s.push(c),
+        }
+    }
+    s.push('"');
+    s
+}
+
+fn unescape(input: &str) -> Option<String> {
+    let mut chars = input.strip_prefix('"')?.strip_suffix('"')?.chars();
+    let mut s = String::new();
+    while let Some(c) = chars.next() {
+        match c {
+            '\\' => s.push(match chars.next()? {
+                'n' => '\n',
+                '"' => '"',
+                '\\' => '\\',
+                _ => return None,
+            }),
+            '"' => return None,
+            c => s.push(c),
+        }
+    }
+    Some(s)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{env, fs, process};
+
+    use super::{Answers, Check};
+
+    #[test]
+    fn parse() {
+        let answers = Answers::new(
+            "# known good
+[1]
+part1 = \"24000\"
+part2 = \"45000\"
+
+[10]
+part2 = \"\\n#.\\\\\\\"\"",
+        )
+        .unwrap();
+        assert_eq!(answers.get(1, 1), Some("24000"));
+        assert_eq!(answers.get(1, 2), Some("45000"));
+        assert_eq!(answers.get(10, 1), None);
+        assert_eq!(answers.get(10, 2), Some("\n#.\\\""));
+    }
+
+    #[test]
+    fn check() {
+        let answers = Answers::new("[2]\npart1 = \"15\"").unwrap();
+        assert_eq!(answers.check(2, 1, "15"), Check::Pass);
+        assert_eq!(answers.check(2, 1, "16"), Check::Fail("15".to_string()));
+        assert_eq!(answers.check(2, 2, "12"), Check::Missing);
+    }
+
+    #[test]
+    fn round_trip() {
+        let mut answers = Answers::default();
+        answers.set(10, 2, "\n##..\n#\"\\");
+        answers.set(2, 1, "15");
+        answers.set(2, 2, "12");
+        assert_eq!(
+            answers.to_string(),
+            "[2]\npart1 = \"15\"\npart2 = \"12\"\n\n[10]\npart2 = \"\\n##..\\n#\\\"\\\\\"\n"
+        );
+        assert_eq!(Answers::new(&answers.to_string()), Ok(answers));
+    }
+
+    #[test]
+    fn invalid() {
+        assert!(Answers::new("part1 = \"15\"").is_err());
+        assert!(Answers::new("[2]\npart3 = \"15\"").is_err());
+        assert!(Answers::new("[2]\npart1 = 15").is_err());
+    }
+
+    #[test]
+    fn load() {
+        let dir = env::temp_dir().join(format!("aoc-2022-answers-{}", process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        assert_eq!(
+            Answers::load(&dir.join("missing.toml")),
+            Ok(Answers::default())
+        );
+        fs::write(dir.join("answers.toml"), "[2]\npart1 = \"15\"").unwrap();
+        assert_eq!(
+            Answers::load(&dir.join("answers.toml")).unwrap().get(2, 1),
+            Some("15")
+        );
+        // not something to quietly write over
+        fs::write(dir.join("binary.toml"), [0xff, 0xfe]).unwrap();
+        assert!(Answers::load(&dir.join("binary.toml")).is_err());
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
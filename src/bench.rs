@@ -0,0 +1,96 @@
+use std::time::{Duration, Instant};
+
+use crate::Result;
+
+// min, median and max of a few timed runs
+#[derive(Debug, PartialEq)]
+pub struct Stats {
+    pub iterations: usize,
+    pub min: Duration,
+    pub median: Duration,
+    pub max: Duration,
+}
+
+impl Stats {
+    pub fn new(mut samples: Vec<Duration>) -> Option<Self> {
+        samples.sort();
+        Some(Self {
+            iterations: samples.len(),
+            min: *samples.first()?,
+            median: samples[samples.len() / 2],
+            max: *samples.last()?,
+        })
+    }
+}
+
+// runs f and returns what it gave back, along with how long it took
+pub fn time<T>(f: impl FnOnce() -> T) -> (T, Duration) {
+    let start = Instant::now();
+    let value = f();
+    (value, start.elapsed())
+}
+
+// runs f some number of times, stopping at the first error
+pub fn bench<T>(iterations: usize, mut f: impl FnMut() -> Result<T>) -> Result<Stats> {
+    let mut samples = vec![];
+    for _ in 0..iterations.max(1) {
+        let (result, elapsed) = time(&mut f);
+        result?;
+        samples.push(elapsed);
+    }
+    Ok(Stats::new(samples).unwrap())
+}
+
+// short enough to fit in a table column
+pub fn format_duration(duration: Duration) -> String {
+    let micros = duration.as_secs_f64() * 1_000_000.0;
+    if micros < 1000.0 {
+        format!("{:.0}µs", micros)
+    } else if micros < 1_000_000.0 {
+        format!("{:.2}ms", micros / 1000.0)
+    } else {
+        format!("{:.2}s", micros / 1_000_000.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::{bench, format_duration, Stats};
+    use crate::Error;
+
+    #[test]
+    fn stats() {
+        let ms = Duration::from_millis;
+        assert_eq!(
+            Stats::new(vec![ms(5), ms(1), ms(9), ms(3), ms(4)]),
+            Some(Stats {
+                iterations: 5,
+                min: ms(1),
+                median: ms(4),
+                max: ms(9)
+            })
+        );
+        assert_eq!(Stats::new(vec![]), None);
+    }
+
+    #[test]
+    fn bench_runs_every_iteration() {
+        let mut runs = 0;
+        let stats = bench(3, || {
+            runs += 1;
+            Ok(())
+        })
+        .unwrap();
+        assert_eq!((runs, stats.iterations), (3, 3));
+        assert!(bench(3, || Err::<(), _>(Error::no_solution("nope"))).is_err());
+    }
+
+    #[test]
+    fn format() {
+        assert_eq!(format_duration(Duration::from_micros(845)), "845µs");
+        assert_eq!(format_duration(Duration::from_micros(12_345)), "12.35ms");
+        assert_eq!(format_duration(Duration::from_millis(2_100)), "2.10s");
+    }
+}
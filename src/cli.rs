@@ -0,0 +1,643 @@
+use std::time::Duration;
+
+use aoc_2022::{valid_profile, Format, InputSource, Params, Registry, DAYS};
+
+#[derive(Debug, PartialEq)]
+pub enum Command {
+    Run {
+        days: Vec<u8>,
+        part: Option<u8>,
+        input: InputSource,
+        params: Params,
+        format: Format,
+        jobs: usize,
+        timeout: Option<Duration>,
+    },
+    Fetch(u8),
+    Check(u8),
+    Compare(u8),
+    Record(Vec<u8>),
+    Verify {
+        timeout: Option<Duration>,
+    },
+    Bench {
+        days: Vec<u8>,
+        part: Option<u8>,
+        iterations: usize,
+    },
+    Repl {
+        day: u8,
+        part: u8,
+        input: InputSource,
+        params: Params,
+    },
+    List,
+    Help,
+}
+
+// pulls `--year YYYY` out from wherever it is in the arguments, since it
+// applies to every command; without it the latest year with solutions is used
+pub fn take_year(args: &mut Vec<String>, known_years: &[u16]) -> Result<u16, String> {
+    let latest = known_years.last().copied().unwrap_or(2022);
+    let Some(arg) = take_option(args, "--year")? else {
+        return Ok(latest);
+    };
+    let year = arg
+        .parse::<u16>()
+        .map_err(|_| format!("\"{}\" is not a year", arg))?;
+    match known_years.contains(&year) {
+        true => Ok(year),
+        false => Err(format!("there are no solutions for {} yet", year)),
+    }
+}
+
+// the same for `--profile NAME`, which picks whose saved inputs to use
+pub fn take_profile(args: &mut Vec<String>) -> Result<Option<String>, String> {
+    match take_option(args, "--profile")? {
+        Some(profile) if !valid_profile(&profile) => Err(format!(
+            "\"{}\" can't be used as a profile name, only letters, digits, - and _ can",
+            profile
+        )),
+        profile => Ok(profile),
+    }
+}
+
+fn take_option(args: &mut Vec<String>, name: &str) -> Result<Option<String>, String> {
+    let Some(i) = args.iter().position(|a| a == name) else {
+        return Ok(None);
+    };
+    args.remove(i);
+    match i < args.len() {
+        true => Ok(Some(args.remove(i))),
+        false => Err(format!("{} needs a value", name)),
+    }
+}
+
+// days are checked against the ones that have a solution, so a typo
+// gets an error instead of quietly running nothing
+pub fn parse(args: &[String], known_days: &[u8]) -> Result<Command, String> {
+    let mut args = args.iter().map(|a| a.as_str());
+    match args.next() {
+        None => Ok(Command::Run {
+            days: known_days.to_vec(),
+            part: None,
+            input: InputSource::Cached,
+            params: Params::default(),
+            format: Format::Text,
+            jobs: 1,
+            timeout: None,
+        }),
+        Some("run") => parse_run(args.collect(), known_days),
+        Some("fetch") => Ok(Command::Fetch(parse_single_day(
+            args.collect(),
+            known_days,
+        )?)),
+        Some("check") => Ok(Command::Check(parse_single_day(
+            args.collect(),
+            known_days,
+        )?)),
+        Some("compare") => Ok(Command::Compare(parse_single_day(
+            args.collect(),
+            known_days,
+        )?)),
+        Some("record") => Ok(Command::Record(parse_days(args.collect(), known_days)?)),
+        Some("verify") => parse_verify(args.collect()),
+        Some("bench") => parse_bench(args.collect(), known_days),
+        Some("repl") => parse_repl(args.collect(), known_days),
+        Some("list") => no_more_args(args.collect(), Command::List),
+        Some("help") | Some("--help") | Some("-h") => Ok(Command::Help),
+        // `cargo run 14` from before there were subcommands
+        Some(arg) if arg == "all" || arg.parse::<u8>().is_ok() => {
+            let mut run_args = vec![arg];
+            run_args.extend(args);
+            parse_run(run_args, known_days)
+        }
+        Some(arg) => Err(format!("unknown command \"{}\"", arg)),
+    }
+}
+
+fn parse_run(args: Vec<&str>, known_days: &[u8]) -> Result<Command, String> {
+    let mut days = vec![];
+    let mut part = None;
+    let mut input = InputSource::Cached;
+    let mut params = Params::default();
+    let mut format = Format::Text;
+    let mut jobs = 1;
+    let mut timeout = None;
+    let mut args = args.into_iter();
+    while let Some(arg) = args.next() {
+        match arg {
+            "--part" => part = parse_part(args.next())?,
+            "--input" => match args.next() {
+                Some(path) => input = InputSource::from_arg(path),
+                None => return Err("--input needs a path, or - for stdin".to_string()),
+            },
+            "--param" => match args.next().map(Params::parse_arg) {
+                Some(Some((name, value))) => params.set(&name, value),
+                Some(None) => return Err("--param needs a name=number pair".to_string()),
+                None => return Err("--param needs a value, like row=10".to_string()),
+            },
+            "--format" => match args.next() {
+                Some(name) => {
+                    format = Format::new(name).ok_or_else(|| {
+                        format!("unknown format \"{}\", expected text, json or csv", name)
+                    })?
+                }
+                None => return Err("--format needs a value".to_string()),
+            },
+            "--jobs" | "-j" => {
+                jobs = match args.next().map(|n| n.parse::<usize>()) {
+                    Some(Ok(n)) if n > 0 => n,
+                    Some(_) => return Err("--jobs needs a positive number".to_string()),
+                    None => return Err("--jobs needs a value".to_string()),
+                }
+            }
+            "--timeout" => timeout = Some(parse_timeout(args.next())?),
+            "-" => input = InputSource::Stdin,
+            "all" => days.extend_from_slice(known_days),
+            day => days.push(parse_day(day, known_days)?),
+        }
+    }
+    if days.is_empty() {
+        return Err("run needs at least one day, or \"all\"".to_string());
+    }
+    if input != InputSource::Cached && days.len() > 1 {
+        return Err("--input can only be used with a single day".to_string());
+    }
+    Ok(Command::Run {
+        days,
+        part,
+        input,
+        params,
+        format,
+        jobs,
+        timeout,
+    })
+}
+
+// stdin is where the repl's commands come from, so the input can't be
+// piped in with `--input -` the way it can for run
+fn parse_repl(args: Vec<&str>, known_days: &[u8]) -> Result<Command, String> {
+    let mut day = None;
+    let mut part = 1;
+    let mut input = InputSource::Cached;
+    let mut params = Params::default();
+    let mut args = args.into_iter();
+    while let Some(arg) = args.next() {
+        match arg {
+            "--part" => part = parse_part(args.next())?.unwrap_or(1),
+            "--input" => match args.next() {
+                Some("-") | None => return Err("--input needs a path".to_string()),
+                Some(path) => input = InputSource::from_arg(path),
+            },
+            "--param" => match args.next().map(Params::parse_arg) {
+                Some(Some((name, value))) => params.set(&name, value),
+                _ => return Err("--param needs a name=number pair".to_string()),
+            },
+            arg if day.is_none() => day = Some(parse_day(arg, known_days)?),
+            arg => return Err(format!("unexpected argument \"{}\"", arg)),
+        }
+    }
+    Ok(Command::Repl {
+        day: day.ok_or("missing day")?,
+        part,
+        input,
+        params,
+    })
+}
+
+fn parse_verify(args: Vec<&str>) -> Result<Command, String> {
+    match args.as_slice() {
+        [] => Ok(Command::Verify { timeout: None }),
+        ["--timeout", rest @ ..] if rest.len() <= 1 => Ok(Command::Verify {
+            timeout: Some(parse_timeout(rest.first().copied())?),
+        }),
+        _ => Err(format!("unexpected argument \"{}\"", args[0])),
+    }
+}
+
+// in seconds, which can have a fraction
+fn parse_timeout(arg: Option<&str>) -> Result<Duration, String> {
+    match arg.map(|s| s.parse::<f64>()) {
+        Some(Ok(secs)) if secs > 0.0 && secs.is_finite() => Ok(Duration::from_secs_f64(secs)),
+        Some(_) => Err("--timeout needs a positive number of seconds".to_string()),
+        None => Err("--timeout needs a value".to_string()),
+    }
+}
+
+// with no days, every day is benchmarked
+fn parse_bench(args: Vec<&str>, known_days: &[u8]) -> Result<Command, String> {
+    let mut days = vec![];
+    let mut part = None;
+    let mut iterations = 10;
+    let mut args = args.into_iter();
+    while let Some(arg) = args.next() {
+        match arg {
+            "--part" => part = parse_part(args.next())?,
+            "--iterations" | "-n" => {
+                iterations = match args.next().map(|n| n.parse::<usize>()) {
+                    Some(Ok(n)) if n > 0 => n,
+                    Some(_) => return Err("--iterations needs a positive number".to_string()),
+                    None => return Err("--iterations needs a value".to_string()),
+                }
+            }
+            "all" => days.extend_from_slice(known_days),
+            day => days.push(parse_day(day, known_days)?),
+        }
+    }
+    if days.is_empty() {
+        days = known_days.to_vec();
+    }
+    Ok(Command::Bench {
+        days,
+        part,
+        iterations,
+    })
+}
+
+fn parse_days(args: Vec<&str>, known_days: &[u8]) -> Result<Vec<u8>, String> {
+    let mut days = vec![];
+    for arg in args {
+        match arg {
+            "all" => days.extend_from_slice(known_days),
+            day => days.push(parse_day(day, known_days)?),
+        }
+    }
+    match days.is_empty() {
+        true => Err("missing day, or \"all\"".to_string()),
+        false => Ok(days),
+    }
+}
+
+fn parse_part(arg: Option<&str>) -> Result<Option<u8>, String> {
+    match arg {
+        Some("1") => Ok(Some(1)),
+        Some("2") => Ok(Some(2)),
+        Some(p) => Err(format!("invalid part \"{}\", expected 1 or 2", p)),
+        None => Err("--part needs a value".to_string()),
+    }
+}
+
+fn parse_single_day(args: Vec<&str>, known_days: &[u8]) -> Result<u8, String> {
+    match args.as_slice() {
+        [day] => parse_day(day, known_days),
+        [] => Err("missing day".to_string()),
+        _ => Err(format!("unexpected argument \"{}\"", args[1])),
+    }
+}
+
+fn parse_day(arg: &str, known_days: &[u8]) -> Result<u8, String> {
+    let day = arg
+        .parse::<u8>()
+        .map_err(|_| format!("\"{}\" is not a day", arg))?;
+    if known_days.contains(&day) {
+        Ok(day)
+    } else if DAYS.contains(&day) {
+        Err(format!("there is no solution for day {} yet", day))
+    } else {
+        Err(format!(
+            "there is no day {}, the puzzles run from day {} to {}",
+            day,
+            DAYS.start(),
+            DAYS.end()
+        ))
+    }
+}
+
+fn no_more_args(args: Vec<&str>, command: Command) -> Result<Command, String> {
+    match args.first() {
+        Some(arg) => Err(format!("unexpected argument \"{}\"", arg)),
+        None => Ok(command),
+    }
+}
+
+pub fn days(registry: &Registry, year: u16) -> String {
+    let mut s = String::new();
+    for p in registry.iter(year) {
+        s.push_str(&format!("  {:>2}  {}\n", p.index(), p.name()));
+        for param in p.params() {
+            let setting = format!("{}={}", param.name, param.default);
+            s.push_str(&format!("        {:<26}  {}\n", setting, param.description));
+        }
+    }
+    let missing: Vec<String> = registry
+        .missing(year)
+        .iter()
+        .map(|d| d.to_string())
+        .collect();
+    if !missing.is_empty() {
+        s.push_str(&format!("  No solution yet: {}\n", missing.join(", ")));
+    }
+    s
+}
+
+pub fn usage(registry: &Registry, year: u16) -> String {
+    let years: Vec<String> = registry.years().iter().map(|y| y.to_string()).collect();
+    format!(
+        "Advent of Code
+
+Usage:
+  aoc-2022 [run] <days...|all> [--part 1|2] [--input PATH|-] [--param NAME=N...]
+                 [--format text|json|csv] [--jobs N] [--timeout SECS]
+  aoc-2022 fetch <day>
+  aoc-2022 check <day>
+  aoc-2022 compare <day>
+  aoc-2022 record <days...|all>
+  aoc-2022 verify [--timeout SECS]
+  aoc-2022 bench [days...|all] [--part 1|2] [--iterations N]
+  aoc-2022 repl <day> [--part 1|2] [--input PATH] [--param NAME=N...]
+  aoc-2022 list
+  aoc-2022 help
+
+Every command also takes --year YYYY and --profile NAME.
+
+Commands:
+  run      print the answers for some days, or all of them
+  fetch    download a day's input into src/data/<year>/<day>/<profile>
+  check    compare a day's answers with the ones in src/data/<year>/answers.toml
+  compare  run a day against every profile's input and show the answers side by side
+  record   save the current answers for some days in src/data/<year>/answers.toml
+  verify   check every day of the year against src/data/<year>/answers.toml
+  bench    time some days (all of them by default) over a few runs
+  repl     step through a day's puzzle a command at a time, for days that have one
+  list     list the days that have a solution
+
+Options:
+  --year YYYY    which year's puzzles to use, one of {} (default {})
+  --profile NAME
+                 use the inputs saved as NAME instead of the default ones,
+                 downloaded with the cookie in $AOC_SESSION_NAME or cookie.NAME,
+                 with answers kept in answers.NAME.toml
+  --part 1|2     only run one part
+  --input PATH   read the input from PATH instead of src/data
+  --input -, -   read the input from stdin
+  --param NAME=N
+                 change one of a day's numbers, like the row on day 15,
+                 the ones each day has are listed under Days
+  --format text|json|csv
+                 how run prints its answers, json and csv include timings
+  --jobs N, -j N
+                 how many days run works on at once (default 1), answers are
+                 still printed in day order
+  --timeout SECS
+                 for run and verify, give up on a day whose parts take longer
+                 than this between them (not counting reading its input),
+                 report it as timed out and carry on with the rest
+  --iterations N, -n N
+                 how many times bench runs each day (default 10)
+
+Days of {}:
+{}",
+        years.join(", "),
+        years.last().map_or("none", |y| y.as_str()),
+        year,
+        days(registry, year)
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{path::PathBuf, time::Duration};
+
+    use aoc_2022::{Format, InputSource, Params};
+
+    use super::{parse, take_profile, take_year, Command};
+
+    fn args(args: &str) -> Vec<String> {
+        args.split_whitespace().map(|a| a.to_string()).collect()
+    }
+
+    #[test]
+    fn run() {
+        let known_days = [1, 2, 3];
+        assert_eq!(
+            parse(&args(""), &known_days),
+            Ok(Command::Run {
+                days: vec![1, 2, 3],
+                part: None,
+                input: InputSource::Cached,
+                params: Params::default(),
+                format: Format::Text,
+                jobs: 1,
+                timeout: None
+            })
+        );
+        assert_eq!(
+            parse(&args("run 3 1 --part 2"), &known_days),
+            Ok(Command::Run {
+                days: vec![3, 1],
+                part: Some(2),
+                input: InputSource::Cached,
+                params: Params::default(),
+                format: Format::Text,
+                jobs: 1,
+                timeout: None
+            })
+        );
+        assert_eq!(
+            parse(&args("02 --input example.txt"), &known_days),
+            Ok(Command::Run {
+                days: vec![2],
+                part: None,
+                input: InputSource::File(PathBuf::from("example.txt")),
+                params: Params::default(),
+                format: Format::Text,
+                jobs: 1,
+                timeout: None
+            })
+        );
+        assert_eq!(
+            parse(&args("3 -"), &known_days),
+            Ok(Command::Run {
+                days: vec![3],
+                part: None,
+                input: InputSource::Stdin,
+                params: Params::default(),
+                format: Format::Text,
+                jobs: 1,
+                timeout: None
+            })
+        );
+        assert_eq!(
+            parse(&args("3 --input -"), &known_days),
+            parse(&args("3 -"), &known_days)
+        );
+        assert_eq!(
+            parse(&args("all"), &known_days),
+            parse(&args("run 1 2 3"), &known_days)
+        );
+        let mut params = Params::default();
+        params.set("row", 10);
+        assert_eq!(
+            parse(&args("run 3 --param row=10"), &known_days),
+            Ok(Command::Run {
+                days: vec![3],
+                part: None,
+                input: InputSource::Cached,
+                params,
+                format: Format::Text,
+                jobs: 1,
+                timeout: None
+            })
+        );
+        assert_eq!(
+            parse(&args("run 1 --format csv"), &known_days),
+            Ok(Command::Run {
+                days: vec![1],
+                part: None,
+                input: InputSource::Cached,
+                params: Params::default(),
+                format: Format::Csv,
+                jobs: 1,
+                timeout: None
+            })
+        );
+        assert_eq!(
+            parse(&args("all -j 4"), &known_days),
+            Ok(Command::Run {
+                days: vec![1, 2, 3],
+                part: None,
+                input: InputSource::Cached,
+                params: Params::default(),
+                format: Format::Text,
+                jobs: 4,
+                timeout: None
+            })
+        );
+        assert_eq!(
+            parse(&args("run 3 --timeout 10"), &known_days),
+            Ok(Command::Run {
+                days: vec![3],
+                part: None,
+                input: InputSource::Cached,
+                params: Params::default(),
+                format: Format::Text,
+                jobs: 1,
+                timeout: Some(Duration::from_secs(10))
+            })
+        );
+    }
+
+    #[test]
+    fn other_commands() {
+        let known_days = [1, 2, 3];
+        assert_eq!(parse(&args("fetch 3"), &known_days), Ok(Command::Fetch(3)));
+        assert_eq!(parse(&args("check 1"), &known_days), Ok(Command::Check(1)));
+        assert_eq!(
+            parse(&args("compare 2"), &known_days),
+            Ok(Command::Compare(2))
+        );
+        assert_eq!(
+            parse(&args("record 2 3"), &known_days),
+            Ok(Command::Record(vec![2, 3]))
+        );
+        assert_eq!(
+            parse(&args("record all"), &known_days),
+            Ok(Command::Record(vec![1, 2, 3]))
+        );
+        assert_eq!(
+            parse(&args("verify"), &known_days),
+            Ok(Command::Verify { timeout: None })
+        );
+        assert_eq!(
+            parse(&args("verify --timeout 2.5"), &known_days),
+            Ok(Command::Verify {
+                timeout: Some(Duration::from_millis(2500))
+            })
+        );
+        assert_eq!(
+            parse(&args("bench"), &known_days),
+            Ok(Command::Bench {
+                days: vec![1, 2, 3],
+                part: None,
+                iterations: 10
+            })
+        );
+        assert_eq!(
+            parse(&args("bench 2 -n 3 --part 1"), &known_days),
+            Ok(Command::Bench {
+                days: vec![2],
+                part: Some(1),
+                iterations: 3
+            })
+        );
+        assert_eq!(
+            parse(&args("repl 2 --part 2"), &known_days),
+            Ok(Command::Repl {
+                day: 2,
+                part: 2,
+                input: InputSource::Cached,
+                params: Params::default()
+            })
+        );
+        assert_eq!(parse(&args("list"), &known_days), Ok(Command::List));
+        assert_eq!(parse(&args("--help"), &known_days), Ok(Command::Help));
+    }
+
+    #[test]
+    fn year() {
+        let known_years = [2021, 2022];
+        let mut with_year = args("run 3 --year 2021 --part 1");
+        assert_eq!(take_year(&mut with_year, &known_years), Ok(2021));
+        assert_eq!(with_year, args("run 3 --part 1"));
+        let mut without_year = args("run 3");
+        assert_eq!(take_year(&mut without_year, &known_years), Ok(2022));
+        assert_eq!(without_year, args("run 3"));
+        assert_eq!(
+            take_year(&mut args("list --year 2015"), &known_years),
+            Err("there are no solutions for 2015 yet".to_string())
+        );
+        assert!(take_year(&mut args("list --year"), &known_years).is_err());
+        assert!(take_year(&mut args("list --year last"), &known_years).is_err());
+    }
+
+    #[test]
+    fn profile() {
+        let mut with_profile = args("--profile alice compare 3");
+        assert_eq!(
+            take_profile(&mut with_profile),
+            Ok(Some("alice".to_string()))
+        );
+        assert_eq!(with_profile, args("compare 3"));
+        assert_eq!(take_profile(&mut args("run 3")), Ok(None));
+        assert!(take_profile(&mut args("run 3 --profile")).is_err());
+        assert!(take_profile(&mut args("run 3 --profile ../x")).is_err());
+    }
+
+    #[test]
+    fn errors() {
+        let known_days = [1, 2, 3];
+        assert_eq!(
+            parse(&args("run 4"), &known_days),
+            Err("there is no solution for day 4 yet".to_string())
+        );
+        assert_eq!(
+            parse(&args("run 26"), &known_days),
+            Err("there is no day 26, the puzzles run from day 1 to 25".to_string())
+        );
+        assert!(parse(&args("run"), &known_days).is_err());
+        assert!(parse(&args("run 1 --part 3"), &known_days).is_err());
+        assert!(parse(&args("run 1 2 --input x.txt"), &known_days).is_err());
+        assert!(parse(&args("run all -"), &known_days).is_err());
+        assert!(parse(&args("run 1 --param row"), &known_days).is_err());
+        assert!(parse(&args("run 1 --param"), &known_days).is_err());
+        assert!(parse(&args("run 1 --format yaml"), &known_days).is_err());
+        assert!(parse(&args("run 1 --jobs 0"), &known_days).is_err());
+        assert!(parse(&args("run 1 --jobs"), &known_days).is_err());
+        assert!(parse(&args("run 1 --timeout 0"), &known_days).is_err());
+        assert!(parse(&args("run 1 --timeout soon"), &known_days).is_err());
+        assert!(parse(&args("verify --timeout"), &known_days).is_err());
+        assert!(parse(&args("fetch"), &known_days).is_err());
+        assert!(parse(&args("fetch 1 2"), &known_days).is_err());
+        assert!(parse(&args("list 1"), &known_days).is_err());
+        assert!(parse(&args("repl"), &known_days).is_err());
+        assert!(parse(&args("repl 1 2"), &known_days).is_err());
+        assert!(parse(&args("repl 1 --input -"), &known_days).is_err());
+        assert!(parse(&args("record"), &known_days).is_err());
+        assert!(parse(&args("record 4"), &known_days).is_err());
+        assert!(parse(&args("verify 1"), &known_days).is_err());
+        assert!(parse(&args("bench --iterations 0"), &known_days).is_err());
+        assert!(parse(&args("bench -n"), &known_days).is_err());
+        assert!(parse(&args("frobnicate"), &known_days).is_err());
+    }
+}
@@ -0,0 +1,156 @@
+use std::{env, fmt, fs, path::PathBuf};
+
+use crate::{Error, Result, DEFAULT_PROFILE};
+
+// a place the session cookie might be kept
+#[derive(Debug, Clone, PartialEq)]
+pub enum CookieSource {
+    Env(String),
+    File(PathBuf),
+}
+
+impl CookieSource {
+    // None if there's nothing there at all
+    fn read(&self) -> Option<String> {
+        match self {
+            Self::Env(name) => env::var(name).ok(),
+            Self::File(path) => fs::read_to_string(path).ok(),
+        }
+        .filter(|s| !s.trim().is_empty())
+    }
+}
+
+impl fmt::Display for CookieSource {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::Env(name) => write!(f, "${}", name),
+            Self::File(path) => write!(f, "{}", path.display()),
+        }
+    }
+}
+
+// looked at in order, the first one that has anything in it wins:
+//
+// 1. the AOC_SESSION environment variable
+// 2. aoc-2022/cookie in the user's config directory ($XDG_CONFIG_HOME,
+//    ~/.config or %APPDATA%)
+// 3. src/data/cookie
+//
+// any profile other than the default one is another account, with its
+// own AOC_SESSION_<PROFILE> variable and cookie.<profile> files
+pub fn cookie_sources(data_dir: PathBuf, profile: &str) -> Vec<CookieSource> {
+    let (var, file) = match profile == DEFAULT_PROFILE {
+        true => ("AOC_SESSION".to_string(), "cookie".to_string()),
+        false => (
+            format!("AOC_SESSION_{}", profile.to_uppercase().replace('-', "_")),
+            format!("cookie.{}", profile),
+        ),
+    };
+    let mut sources = vec![CookieSource::Env(var)];
+    if let Some(config_dir) = config_dir() {
+        sources.push(CookieSource::File(config_dir.join("aoc-2022").join(&file)));
+    }
+    sources.push(CookieSource::File(data_dir.join(file)));
+    sources
+}
+
+fn config_dir() -> Option<PathBuf> {
+    let var = |name| {
+        env::var_os(name)
+            .filter(|v| !v.is_empty())
+            .map(PathBuf::from)
+    };
+    var("XDG_CONFIG_HOME")
+        .or_else(|| var("HOME").map(|home| home.join(".config")))
+        .or_else(|| var("APPDATA"))
+}
+
+// the cookie header to send, from the first source that has one
+pub fn find_cookie(sources: &[CookieSource]) -> Result<String> {
+    for source in sources {
+        if let Some(value) = source.read() {
+            return parse_cookie(&value).ok_or_else(|| {
+                Error::input(&format!(
+                    "the cookie in {} should look like session=<hex digits>",
+                    source
+                ))
+            });
+        }
+    }
+    let places: Vec<String> = sources.iter().map(|s| s.to_string()).collect();
+    Err(Error::input(&format!(
+        "no session cookie found, set one in {}",
+        places.join(", or ")
+    )))
+}
+
+// accepts "session=<token>" or just the token, which is hex digits
+fn parse_cookie(value: &str) -> Option<String> {
+    let value = value.trim();
+    let token = value.strip_prefix("session=").unwrap_or(value);
+    match !token.is_empty() && token.chars().all(|c| c.is_ascii_hexdigit()) {
+        true => Some(format!("session={}", token)),
+        false => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{env, fs, path::PathBuf, process};
+
+    use super::{cookie_sources, find_cookie, parse_cookie, CookieSource};
+
+    #[test]
+    fn parse() {
+        assert_eq!(
+            parse_cookie("session=53a1f\n"),
+            Some("session=53a1f".to_string())
+        );
+        assert_eq!(parse_cookie("53a1f"), Some("session=53a1f".to_string()));
+        assert_eq!(parse_cookie("session="), None);
+        assert_eq!(parse_cookie("<html>"), None);
+        assert_eq!(parse_cookie("session=53a1f; other=1"), None);
+    }
+
+    #[test]
+    fn profiles() {
+        let data_dir = PathBuf::from("data");
+        let sources = cookie_sources(data_dir.clone(), "default");
+        assert_eq!(sources[0], CookieSource::Env("AOC_SESSION".to_string()));
+        assert_eq!(
+            sources.last(),
+            Some(&CookieSource::File(data_dir.join("cookie")))
+        );
+        let sources = cookie_sources(data_dir.clone(), "work-account");
+        assert_eq!(
+            sources[0],
+            CookieSource::Env("AOC_SESSION_WORK_ACCOUNT".to_string())
+        );
+        assert_eq!(
+            sources.last(),
+            Some(&CookieSource::File(data_dir.join("cookie.work-account")))
+        );
+    }
+
+    #[test]
+    fn precedence() {
+        let dir = env::temp_dir().join(format!("aoc-2022-cookie-{}", process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        fs::write(dir.join("first"), "session=aaa").unwrap();
+        fs::write(dir.join("second"), "bbb").unwrap();
+        fs::write(dir.join("bad"), "not a cookie").unwrap();
+        let source = |name: &str| CookieSource::File(dir.join(name));
+
+        assert_eq!(
+            find_cookie(&[source("missing"), source("first"), source("second")]),
+            Ok("session=aaa".to_string())
+        );
+        assert_eq!(
+            find_cookie(&[source("second"), source("first")]),
+            Ok("session=bbb".to_string())
+        );
+        assert!(find_cookie(&[source("bad"), source("first")]).is_err());
+        assert!(find_cookie(&[source("missing")]).is_err());
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
@@ -0,0 +1,251 @@
+use std::collections::HashSet;
+
+use crate::{parse_lines, DailyProblem, Error, Example, Param, Params, Result};
+use lazy_static::lazy_static;
+use regex::Regex;
+
+pub struct BeaconExclusionZone;
+
+const ROW: Param = Param {
+    name: "row",
+    default: 2000000,
+    description: "the row to count positions in for part 1",
+};
+const BOUND: Param = Param {
+    name: "bound",
+    default: 4000000,
+    description: "the largest x and y the distress beacon can have",
+};
+
+impl DailyProblem for BeaconExclusionZone {
+    fn name(&self) -> &str {
+        "Day 15: Beacon Exclusion Zone"
+    }
+    fn index(&self) -> u8 {
+        15
+    }
+    fn params(&self) -> &[Param] {
+        &[ROW, BOUND]
+    }
+    fn part_one(&self, input: &str, params: &Params) -> Result<String> {
+        let pairs = parse_lines(input, SensorBeaconPair::new)?;
+        Ok(no_beacons_at_row(&pairs, params.get_as(&ROW)?).to_string())
+    }
+    fn part_two(&self, input: &str, params: &Params) -> Result<String> {
+        let pairs = parse_lines(input, SensorBeaconPair::new)?;
+        Ok(diagonals(&pairs, params.get_as(&BOUND)?)
+            .ok_or_else(|| Error::no_solution("there is no single gap for the distress beacon"))?
+            .to_string())
+    }
+    fn example(&self) -> Option<Example> {
+        Some(Example {
+            input: EXAMPLE,
+            part_one: "26",
+            part_two: "56000011",
+            params: &[("row", 10), ("bound", 20)],
+        })
+    }
+}
+
+const EXAMPLE: &str = "Sensor at x=2, y=18: closest beacon is at x=-2, y=15
+Sensor at x=9, y=16: closest beacon is at x=10, y=16
+Sensor at x=13, y=2: closest beacon is at x=15, y=3
+Sensor at x=12, y=14: closest beacon is at x=10, y=16
+Sensor at x=10, y=20: closest beacon is at x=10, y=16
+Sensor at x=14, y=17: closest beacon is at x=10, y=16
+Sensor at x=8, y=7: closest beacon is at x=2, y=10
+Sensor at x=2, y=0: closest beacon is at x=2, y=10
+Sensor at x=0, y=11: closest beacon is at x=2, y=10
+Sensor at x=20, y=14: closest beacon is at x=25, y=17
+Sensor at x=17, y=20: closest beacon is at x=21, y=22
+Sensor at x=16, y=7: closest beacon is at x=15, y=3
+Sensor at x=14, y=3: closest beacon is at x=15, y=3
+Sensor at x=20, y=1: closest beacon is at x=15, y=3";
+
+// the beacon sits in a one-wide gap between two sensors' edges in both
+// directions, so it's where one of those gaps crosses one of the others;
+// a crossing outside 0..=bound, or that some sensor covers, doesn't count
+pub fn diagonals(pairs: &[SensorBeaconPair], bound: i32) -> Option<i64> {
+    let mut diagonals = vec![];
+    pairs
+        .iter()
+        .map(|sbp| sbp.diagonals())
+        .for_each(|mut ds| diagonals.append(&mut ds));
+    diagonals.sort();
+    let mut rising = vec![];
+    let mut falling = vec![];
+    for (i, a) in diagonals.iter().enumerate() {
+        for b in &diagonals[i + 1..] {
+            if a.positive_slope == b.positive_slope && b.y_intercept - a.y_intercept == 2 {
+                match a.positive_slope {
+                    false => rising.push(a.y_intercept + 1),
+                    true => falling.push(a.y_intercept + 1),
+                }
+            }
+        }
+    }
+    for a in &rising {
+        for b in &falling {
+            if (a + b) % 2 != 0 {
+                continue;
+            }
+            let y = (a + b) / 2;
+            let x = (b - a) / 2;
+            let in_bounds = (0..=bound).contains(&x) && (0..=bound).contains(&y);
+            if in_bounds && !pairs.iter().any(|sbp| sbp.covers(x, y)) {
+                return Some(((x as i64) * 4000000) + (y as i64));
+            }
+        }
+    }
+    None
+}
+
+#[derive(Debug)]
+struct Range(i32, i32);
+pub fn no_beacons_at_row(pairs: &[SensorBeaconPair], row: i32) -> u32 {
+    let ranges: Vec<Range> = pairs
+        .iter()
+        .filter_map(|sbp| sbp.range_at_row(row))
+        .collect();
+    let mut min = i32::MAX;
+    let mut max = i32::MIN;
+    for range in &ranges {
+        if range.0 < min {
+            min = range.0
+        }
+        if range.1 > max {
+            max = range.1
+        }
+    }
+    let mut sum = 0;
+    for x in min..=max {
+        for range in &ranges {
+            if x >= range.0 && x <= range.1 {
+                sum += 1;
+                break;
+            }
+        }
+    }
+
+    // a beacon on the row is always inside its own sensor's range, so
+    // each one was counted above, however many sensors share it
+    let beacons_in_row: HashSet<_> = pairs
+        .iter()
+        .map(|sbp| &sbp.1)
+        .filter(|pos| pos.1 == row)
+        .collect();
+
+    sum - beacons_in_row.len() as u32
+}
+
+#[derive(Debug, PartialEq, Eq, Hash)]
+struct Position(i32, i32);
+
+#[derive(Debug)]
+pub struct SensorBeaconPair(Position, Position);
+
+impl SensorBeaconPair {
+    pub fn new(input: &str) -> Option<Self> {
+        lazy_static! {
+            static ref RE: Regex = Regex::new(
+                "Sensor at x=(-?\\d*), y=(-?\\d*): closest beacon is at x=(-?\\d*), y=(-?\\d*)"
+            )
+            .unwrap();
+        }
+        let caps = RE.captures(input)?;
+        Some(Self(
+            Position(
+                caps.get(1)?.as_str().parse().ok()?,
+                caps.get(2)?.as_str().parse().ok()?,
+            ),
+            Position(
+                caps.get(3)?.as_str().parse().ok()?,
+                caps.get(4)?.as_str().parse().ok()?,
+            ),
+        ))
+    }
+
+    fn distance(&self) -> i32 {
+        (self.0 .0 - self.1 .0).abs() + (self.0 .1 - self.1 .1).abs()
+    }
+
+    fn covers(&self, x: i32, y: i32) -> bool {
+        (self.0 .0 - x).abs() + (self.0 .1 - y).abs() <= self.distance()
+    }
+
+    fn range_at_row(&self, row: i32) -> Option<Range> {
+        let sen_bea_dist = self.distance();
+        let sen_y_dist = (self.0 .1 - row).abs();
+        let half_range_width = sen_bea_dist - sen_y_dist;
+        if half_range_width < 0 {
+            None
+        } else {
+            Some(Range(
+                self.0 .0 - half_range_width,
+                self.0 .0 + half_range_width,
+            ))
+        }
+    }
+
+    fn diagonals(&self) -> Vec<Diagonal> {
+        vec![
+            Diagonal {
+                positive_slope: false,
+                y_intercept: self.0 .1 - self.0 .0 + self.distance(),
+            },
+            Diagonal {
+                positive_slope: true,
+                y_intercept: self.0 .1 + self.0 .0 - self.distance(),
+            },
+            Diagonal {
+                positive_slope: false,
+                y_intercept: self.0 .1 - self.0 .0 - self.distance(),
+            },
+            Diagonal {
+                positive_slope: true,
+                y_intercept: self.0 .1 + self.0 .0 + self.distance(),
+            },
+        ]
+    }
+}
+
+#[derive(Debug, PartialEq, PartialOrd, Ord, Eq)]
+struct Diagonal {
+    positive_slope: bool,
+    y_intercept: i32,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{diagonals, no_beacons_at_row, SensorBeaconPair, EXAMPLE};
+    use crate::parse_lines;
+
+    #[test]
+    fn row() {
+        let pairs = parse_lines(EXAMPLE, SensorBeaconPair::new).unwrap();
+        assert_eq!(no_beacons_at_row(&pairs, 10), 26);
+        // no beacons on row 11, and one that four sensors share on row 16
+        for row in [11, 16] {
+            let naive = (-20..=40)
+                .filter(|&x| pairs.iter().any(|sbp| sbp.covers(x, row)))
+                .filter(|&x| !pairs.iter().any(|sbp| sbp.1 .0 == x && sbp.1 .1 == row))
+                .count();
+            assert_eq!(no_beacons_at_row(&pairs, row), naive as u32);
+        }
+        // no sensor reaches this far
+        assert_eq!(no_beacons_at_row(&pairs, 1000), 0);
+        assert_eq!(no_beacons_at_row(&[], 10), 0);
+        // two beacons on the same row
+        let input = "Sensor at x=0, y=0: closest beacon is at x=2, y=0
+Sensor at x=10, y=0: closest beacon is at x=8, y=0";
+        let pairs = parse_lines(input, SensorBeaconPair::new).unwrap();
+        assert_eq!(no_beacons_at_row(&pairs, 0), 8);
+    }
+
+    #[test]
+    fn distress_beacon() {
+        let pairs = parse_lines(EXAMPLE, SensorBeaconPair::new).unwrap();
+        assert_eq!(diagonals(&pairs, 20), Some(56000011));
+        assert_eq!(diagonals(&pairs, 5), None);
+    }
+}
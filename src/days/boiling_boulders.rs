@@ -1,6 +1,6 @@
 use std::collections::HashSet;
 
-use crate::DailyProblem;
+use crate::{parse_lines, DailyProblem, Example, Params, Result};
 
 pub struct BoilingBoulders;
 
@@ -11,14 +11,36 @@ impl DailyProblem for BoilingBoulders {
     fn index(&self) -> u8 {
         18
     }
-    fn solutions(&self, input: &str) -> (String, String) {
-        (
-            total_surface_area(cubes_from_string(input)).to_string(),
-            total_external_surface_area(input).to_string(),
-        )
+    fn part_one(&self, input: &str, _params: &Params) -> Result<String> {
+        Ok(total_surface_area(cubes_from_string(input)?).to_string())
+    }
+    fn part_two(&self, input: &str, _params: &Params) -> Result<String> {
+        Ok(total_external_surface_area(input)?.to_string())
+    }
+    fn example(&self) -> Option<Example> {
+        Some(Example {
+            input: EXAMPLE,
+            part_one: "64",
+            part_two: "58",
+            params: &[],
+        })
     }
 }
 
+const EXAMPLE: &str = "2,2,2
+1,2,2
+3,2,2
+2,1,2
+2,3,2
+2,2,1
+2,2,3
+2,2,4
+2,2,6
+1,2,5
+3,2,5
+2,1,5
+2,3,5";
+
 // part 1
 // a cube at 1,1,1 has six sides
 // let's assume it spans from point 1,1,1 to 2,2,2
@@ -30,11 +52,11 @@ impl DailyProblem for BoilingBoulders {
 // if one of these sides already exists, though, it means
 // that it's no longer on the surface and should be removed
 
-fn cubes_from_string(input: &str) -> Vec<Cube> {
-    input.lines().map(Cube::new).collect()
+pub fn cubes_from_string(input: &str) -> Result<Vec<Cube>> {
+    parse_lines(input, Cube::new)
 }
 
-fn total_surface_area(cubes: Vec<Cube>) -> usize {
+pub fn total_surface_area(cubes: Vec<Cube>) -> usize {
     let mut x_sides = HashSet::new();
     let mut y_sides = HashSet::new();
     let mut z_sides = HashSet::new();
@@ -78,8 +100,8 @@ fn total_surface_area(cubes: Vec<Cube>) -> usize {
 // the initial cubes, then subtract the result of the total
 // surface area of all of the in cubes
 
-fn total_external_surface_area(input: &str) -> usize {
-    let rocks = cubes_from_string(input);
+pub fn total_external_surface_area(input: &str) -> Result<usize> {
+    let rocks = cubes_from_string(input)?;
     let mut min_x = 0;
     let mut max_x = 0;
     let mut min_y = 0;
@@ -282,7 +304,7 @@ fn total_external_surface_area(input: &str) -> usize {
         }
     }
 
-    total_surface_area(cubes_from_string(input)) - total_surface_area(in_set.drain().collect())
+    Ok(total_surface_area(rocks) - total_surface_area(in_set.drain().collect()))
 }
 
 #[derive(PartialEq, Debug)]
@@ -299,19 +321,19 @@ impl Cell {
 }
 
 #[derive(Eq, PartialEq, Hash, Debug)]
-struct Cube(usize, usize, usize);
+pub struct Cube(usize, usize, usize);
 
 #[derive(Eq, PartialEq, Hash)]
 struct Side(usize, usize, usize);
 
 impl Cube {
-    fn new(input: &str) -> Self {
-        let mut chunks = input.split(',').map(|c| c.parse::<usize>().unwrap());
-        Cube(
-            chunks.next().unwrap(),
-            chunks.next().unwrap(),
-            chunks.next().unwrap(),
-        )
+    pub fn new(input: &str) -> Option<Self> {
+        let mut chunks = input.split(',').map(|c| c.parse::<usize>().ok());
+        let cube = Cube(chunks.next()??, chunks.next()??, chunks.next()??);
+        match chunks.next() {
+            Some(_) => None,
+            None => Some(cube),
+        }
     }
 
     fn x_sides(&self) -> Vec<Side> {
@@ -337,7 +359,7 @@ impl Cube {
 
 #[cfg(test)]
 mod tests {
-    use crate::boiling_boulders::{
+    use crate::days::boiling_boulders::{
         cubes_from_string, total_external_surface_area, total_surface_area,
     };
 
@@ -345,7 +367,7 @@ mod tests {
     fn smol() {
         let input = "1,1,1
 2,1,1";
-        assert_eq!(total_surface_area(cubes_from_string(input)), 10);
+        assert_eq!(total_surface_area(cubes_from_string(input).unwrap()), 10);
     }
 
     #[test]
@@ -363,7 +385,7 @@ mod tests {
 3,2,5
 2,1,5
 2,3,5";
-        assert_eq!(total_surface_area(cubes_from_string(input)), 64);
+        assert_eq!(total_surface_area(cubes_from_string(input).unwrap()), 64);
     }
     #[test]
     fn in_set() {
@@ -380,6 +402,6 @@ mod tests {
 3,2,5
 2,1,5
 2,3,5";
-        assert_eq!(total_external_surface_area(input), 58);
+        assert_eq!(total_external_surface_area(input), Ok(58));
     }
 }
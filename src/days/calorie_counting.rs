@@ -0,0 +1,78 @@
+use crate::{parse_blocks, DailyProblem, Error, Example, Params, Result};
+
+pub struct CalorieCounting;
+
+impl DailyProblem for CalorieCounting {
+    fn name(&self) -> &str {
+        "Day 1: Calorie Counting"
+    }
+    fn index(&self) -> u8 {
+        1
+    }
+    fn part_one(&self, input: &str, _params: &Params) -> Result<String> {
+        let calories = group_by_elf(input)?;
+        let max_calories = calories.iter().max();
+        Ok(max_calories.unwrap().to_string())
+    }
+    fn part_two(&self, input: &str, _params: &Params) -> Result<String> {
+        let mut calories = group_by_elf(input)?;
+        if calories.len() < 3 {
+            return Err(Error::no_solution("there are fewer than three elves"));
+        }
+        calories.sort();
+        let top_three = &calories[calories.len() - 3..calories.len()];
+        Ok(top_three.iter().sum::<u32>().to_string())
+    }
+    fn example(&self) -> Option<Example> {
+        Some(Example {
+            input: EXAMPLE,
+            part_one: "24000",
+            part_two: "45000",
+            params: &[],
+        })
+    }
+}
+
+const EXAMPLE: &str = "1000
+2000
+3000
+
+4000
+
+5000
+6000
+
+7000
+8000
+9000
+
+10000";
+
+pub fn group_by_elf(input: &str) -> Result<Vec<u32>> {
+    parse_blocks(input, |elf| {
+        elf.lines().map(|l| l.parse::<u32>().ok()).sum()
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn test_group_by_elf() {
+        let input = "1000
+2000
+3000
+
+4000
+
+5000
+6000
+
+7000
+8000
+9000
+
+10000";
+        let calories = vec![6000, 4000, 11000, 24000, 10000];
+        assert_eq!(super::group_by_elf(input), Ok(calories));
+    }
+}
@@ -1,4 +1,4 @@
-use aoc_2022::{sum_lines, DailyProblem};
+use crate::{sum_lines, DailyProblem, Example, Params, Result};
 use lazy_static::lazy_static;
 use regex::Regex;
 use std::cmp::Ordering;
@@ -13,28 +13,40 @@ impl DailyProblem for CampCleanup {
     fn index(&self) -> u8 {
         4
     }
-    fn solutions(&self, input: &str) -> (String, String) {
-        (
-            sum_lines(input, count_full_overlaps).to_string(),
-            sum_lines(input, count_partial_overlaps).to_string(),
-        )
+    fn part_one(&self, input: &str, _params: &Params) -> Result<String> {
+        Ok(sum_lines(input, count_full_overlaps)?.to_string())
+    }
+    fn part_two(&self, input: &str, _params: &Params) -> Result<String> {
+        Ok(sum_lines(input, count_partial_overlaps)?.to_string())
+    }
+    fn example(&self) -> Option<Example> {
+        Some(Example {
+            input: EXAMPLE,
+            part_one: "2",
+            part_two: "4",
+            params: &[],
+        })
     }
 }
 
-fn count_full_overlaps(input: &str) -> u32 {
-    match AssignmentPair::from_str(input).unwrap().is_full_overlap() {
-        true => 1,
-        false => 0,
+const EXAMPLE: &str = "2-4,6-8
+2-3,4-5
+5-7,7-9
+2-8,3-7
+6-6,4-6
+2-6,4-8";
+
+pub fn count_full_overlaps(input: &str) -> Option<u32> {
+    match AssignmentPair::from_str(input).ok()?.is_full_overlap() {
+        true => Some(1),
+        false => Some(0),
     }
 }
 
-fn count_partial_overlaps(input: &str) -> u32 {
-    match AssignmentPair::from_str(input)
-        .unwrap()
-        .is_partial_overlap()
-    {
-        true => 1,
-        false => 0,
+pub fn count_partial_overlaps(input: &str) -> Option<u32> {
+    match AssignmentPair::from_str(input).ok()?.is_partial_overlap() {
+        true => Some(1),
+        false => Some(0),
     }
 }
 
@@ -44,21 +56,13 @@ struct AssignmentPair((u32, u32), (u32, u32));
 impl FromStr for AssignmentPair {
     type Err = ();
 
-    fn from_str(input: &str) -> Result<Self, ()> {
+    fn from_str(input: &str) -> std::result::Result<Self, ()> {
         lazy_static! {
             static ref RE: Regex = Regex::new("(\\d*)-(\\d*),(\\d*)-(\\d*)").unwrap();
         }
-        let caps = RE.captures(input).unwrap();
-        Ok(Self(
-            (
-                (caps.get(1).unwrap().as_str().parse().unwrap()),
-                (caps.get(2).unwrap().as_str().parse().unwrap()),
-            ),
-            (
-                (caps.get(3).unwrap().as_str().parse().unwrap()),
-                (caps.get(4).unwrap().as_str().parse().unwrap()),
-            ),
-        ))
+        let caps = RE.captures(input).ok_or(())?;
+        let number = |i| caps.get(i).unwrap().as_str().parse().map_err(|_| ());
+        Ok(Self((number(1)?, number(2)?), (number(3)?, number(4)?)))
     }
 }
 
@@ -82,7 +86,7 @@ impl AssignmentPair {
 
 #[cfg(test)]
 mod tests {
-    use crate::camp_cleanup::AssignmentPair;
+    use crate::days::camp_cleanup::AssignmentPair;
     use std::str::FromStr;
 
     #[test]
@@ -1,4 +1,4 @@
-use crate::DailyProblem;
+use crate::{parse_blocks, DailyProblem, Example, Params, Result};
 
 pub struct DistressSignal;
 
@@ -9,33 +9,79 @@ impl DailyProblem for DistressSignal {
     fn index(&self) -> u8 {
         13
     }
-    fn solutions(&self, input: &str) -> (String, String) {
-        solve(input)
+    fn part_one(&self, input: &str, _params: &Params) -> Result<String> {
+        Ok(ordered_pairs(&parse_pairs(input)?).to_string())
+    }
+    fn part_two(&self, input: &str, _params: &Params) -> Result<String> {
+        Ok(decoder_key(parse_pairs(input)?).to_string())
+    }
+    fn example(&self) -> Option<Example> {
+        Some(Example {
+            input: EXAMPLE,
+            part_one: "13",
+            part_two: "140",
+            params: &[],
+        })
     }
 }
 
+const EXAMPLE: &str = "[1,1,3,1,1]
+[1,1,5,1,1]
+
+[[1],[2,3,4]]
+[[1],4]
+
+[9]
+[[8,7,6]]
+
+[[4,4],4,4]
+[[4,4],4,4,4]
+
+[7,7,7,7]
+[7,7,7]
+
+[]
+[3]
+
+[[[]]]
+[[]]
+
+[1,[2,[3,[4,[5,6,7]]]],8,9]
+[1,[2,[3,[4,[5,6,0]]]],8,9]";
+
 use core::cmp::Ordering;
 
-fn solve(input: &str) -> (String, String) {
-    let pairs = input.split("\n\n");
+pub fn parse_pairs(input: &str) -> Result<Vec<(Node, Node)>> {
+    parse_blocks(input, |pair| {
+        let mut lines = pair.lines();
+        let pair = (Node::new(lines.next()?)?, Node::new(lines.next()?)?);
+        match lines.next() {
+            Some(_) => None,
+            None => Some(pair),
+        }
+    })
+}
+
+pub fn ordered_pairs(pairs: &[(Node, Node)]) -> usize {
+    let mut correct = 0;
+    for (index, (a, b)) in pairs.iter().enumerate() {
+        // println!("a: {:?}, b: {:?}, cmp: {:?}", a, b, a < b);
+        if a < b {
+            correct += index + 1
+        };
+    }
+    correct
+}
+
+pub fn decoder_key(pairs: Vec<(Node, Node)>) -> usize {
     let divider_packet_one = Node::List(vec![Node::List(vec![Node::Integer(2)])]);
     let divider_packet_two = Node::List(vec![Node::List(vec![Node::Integer(6)])]);
 
     let mut all_packets = vec![divider_packet_one, divider_packet_two];
-    let mut correct = 0;
-    for (index, pair) in pairs.enumerate() {
-        let mut lines = pair.lines();
-        let a = Node::new(lines.next().unwrap());
-        let b = Node::new(lines.next().unwrap());
-        let cmp = a < b;
+    for (a, b) in pairs {
         all_packets.push(a);
         all_packets.push(b);
-        // println!("a: {:?}, b: {:?}, cmp: {:?}", a, b, cmp);
-        if cmp {
-            correct += index + 1
-        };
     }
-    // println!("{}", correct);
     all_packets.sort();
 
     let mut decoder_key = 1;
@@ -48,33 +94,31 @@ fn solve(input: &str) -> (String, String) {
         }
     }
     //println!("{}", decoder_key);
-    (correct.to_string(), decoder_key.to_string())
+    decoder_key
 }
 
 #[derive(Debug, PartialEq, Eq)]
-enum Node {
+pub enum Node {
     List(Vec<Node>),
     Integer(u32),
 }
 
 impl Node {
-    fn new(input: &str) -> Self {
+    pub fn new(input: &str) -> Option<Self> {
         if input.is_empty() {
-            return Node::List(vec![]);
+            return Some(Node::List(vec![]));
         }
         let mut chars = input.chars();
-        if chars.next().unwrap() == '[' {
+        if chars.next()? == '[' {
             let mut list = vec![];
             let mut bracket_level = 0;
             let mut start_char_index = 1;
-            let mut char_index = 1;
-            for c in chars {
+            for (char_index, c) in chars.enumerate() {
+                let char_index = char_index + 1;
                 match c {
-                    ',' => {
-                        if bracket_level == 0 {
-                            list.push(&input[start_char_index..char_index]);
-                            start_char_index = char_index + 1;
-                        }
+                    ',' if bracket_level == 0 => {
+                        list.push(&input[start_char_index..char_index]);
+                        start_char_index = char_index + 1;
                     }
                     ']' => {
                         if bracket_level == 0 {
@@ -89,11 +133,12 @@ impl Node {
                     }
                     _ => {}
                 }
-                char_index += 1;
             }
-            Self::List(list.into_iter().map(Node::new).collect())
+            Some(Self::List(
+                list.into_iter().map(Node::new).collect::<Option<_>>()?,
+            ))
         } else {
-            Self::Integer(input.parse().unwrap())
+            Some(Self::Integer(input.parse().ok()?))
         }
     }
 }
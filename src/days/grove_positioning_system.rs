@@ -0,0 +1,228 @@
+use crate::{parse_lines, DailyProblem, Error, Example, Param, Params, Result};
+
+pub struct GrovePositioningSystem;
+
+const DECRYPTION_KEY: Param = Param {
+    name: "decryption_key",
+    default: 811589153,
+    description: "what every number is multiplied by in part 2",
+};
+const PART_2_ROUNDS: Param = Param {
+    name: "part2_rounds",
+    default: 10,
+    description: "how many times the numbers are mixed in part 2",
+};
+
+impl DailyProblem for GrovePositioningSystem {
+    fn name(&self) -> &str {
+        "Day 20: Grove Positioning System"
+    }
+    fn index(&self) -> u8 {
+        20
+    }
+    fn params(&self) -> &[Param] {
+        &[DECRYPTION_KEY, PART_2_ROUNDS]
+    }
+    fn part_one(&self, input: &str, _params: &Params) -> Result<String> {
+        let mut list = MixingList::new(parse_numbers(input)?);
+        list.mix();
+        Ok(list.grove_coordinates()?.to_string())
+    }
+    fn part_two(&self, input: &str, params: &Params) -> Result<String> {
+        let key: i64 = params.get_as(&DECRYPTION_KEY)?;
+        let numbers = parse_numbers(input)?
+            .iter()
+            .map(|n| n.checked_mul(key))
+            .collect::<Option<_>>()
+            .ok_or_else(|| Error::no_solution("the decryption key makes a number too big"))?;
+        let mut list = MixingList::new(numbers);
+        for _ in 0..params.get(&PART_2_ROUNDS) {
+            list.mix();
+        }
+        Ok(list.grove_coordinates()?.to_string())
+    }
+    fn example(&self) -> Option<Example> {
+        Some(Example {
+            input: EXAMPLE,
+            part_one: "3",
+            part_two: "1623178306",
+            params: &[],
+        })
+    }
+}
+
+const EXAMPLE: &str = "1
+2
+-3
+3
+-2
+0
+4";
+
+pub fn parse_numbers(input: &str) -> Result<Vec<i64>> {
+    parse_lines(input, |line| line.trim().parse().ok())
+}
+
+// the numbers in their current order, split into blocks of about sqrt(n)
+// each, so that finding, taking out and putting back a number costs
+// O(sqrt n) instead of O(n) for one flat list. numbers are kept as their
+// index in the original order, since the same value can turn up more
+// than once
+#[derive(Debug)]
+pub struct MixingList {
+    values: Vec<i64>,
+    blocks: Vec<Vec<usize>>,
+    block_of: Vec<usize>,
+    block_size: usize,
+}
+
+impl MixingList {
+    pub fn new(values: Vec<i64>) -> Self {
+        let block_size = ((values.len() as f64).sqrt() as usize).max(1);
+        let mut list = Self {
+            block_of: vec![0; values.len()],
+            values,
+            blocks: vec![],
+            block_size,
+        };
+        list.rebuild((0..list.values.len()).collect());
+        list
+    }
+
+    fn rebuild(&mut self, order: Vec<usize>) {
+        self.blocks = order
+            .chunks(self.block_size)
+            .map(|chunk| chunk.to_vec())
+            .collect();
+        for (block_index, block) in self.blocks.iter().enumerate() {
+            for &index in block {
+                self.block_of[index] = block_index;
+            }
+        }
+    }
+
+    // moves every number once, in their original order
+    pub fn mix(&mut self) {
+        for index in 0..self.values.len() {
+            self.move_number(index);
+        }
+    }
+
+    // moves the number that started at `index` forward or back by its value
+    fn move_number(&mut self, index: usize) {
+        let len = self.values.len();
+        if len < 2 {
+            return;
+        }
+        let position = self.take(index);
+        // with the number taken out there are len - 1 others to go around,
+        // and the value is brought down to one lap first so it can't overflow
+        let others = len as i64 - 1;
+        let new_position =
+            (position as i64 + self.values[index].rem_euclid(others)).rem_euclid(others);
+        self.insert(new_position as usize, index);
+    }
+
+    // takes a number out, returning the position it was at
+    fn take(&mut self, index: usize) -> usize {
+        let block_index = self.block_of[index];
+        let before: usize = self.blocks[..block_index].iter().map(|b| b.len()).sum();
+        let block = &mut self.blocks[block_index];
+        let offset = block.iter().position(|&i| i == index).unwrap();
+        block.remove(offset);
+        before + offset
+    }
+
+    fn insert(&mut self, mut position: usize, index: usize) {
+        let mut block_index = 0;
+        while block_index < self.blocks.len() - 1 && position > self.blocks[block_index].len() {
+            position -= self.blocks[block_index].len();
+            block_index += 1;
+        }
+        self.blocks[block_index].insert(position, index);
+        self.block_of[index] = block_index;
+        // numbers pile up in some blocks over time, so every so often
+        // they're spread out evenly again
+        if self.blocks[block_index].len() > self.block_size * 2 {
+            let order = self.blocks.concat();
+            self.rebuild(order);
+        }
+    }
+
+    pub fn order(&self) -> Vec<i64> {
+        self.blocks
+            .iter()
+            .flatten()
+            .map(|&index| self.values[index])
+            .collect()
+    }
+
+    // the 1000th, 2000th and 3000th numbers after the 0, added up
+    pub fn grove_coordinates(&self) -> Result<i64> {
+        let order = self.order();
+        let zero = order
+            .iter()
+            .position(|&n| n == 0)
+            .ok_or_else(|| Error::no_solution("there is no 0 in the list"))?;
+        [1000, 2000, 3000]
+            .iter()
+            .try_fold(0i64, |sum, offset| {
+                sum.checked_add(order[(zero + offset) % order.len()])
+            })
+            .ok_or_else(|| Error::no_solution("the grove coordinates add up to too much"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{parse_numbers, GrovePositioningSystem, MixingList, EXAMPLE};
+    use crate::{DailyProblem, Params};
+
+    // the list is circular, so orders are compared starting from the 0
+    fn from_zero(list: &MixingList) -> Vec<i64> {
+        let mut order = list.order();
+        let zero = order.iter().position(|&n| n == 0).unwrap();
+        order.rotate_left(zero);
+        order
+    }
+
+    #[test]
+    fn mix() {
+        let mut list = MixingList::new(parse_numbers(EXAMPLE).unwrap());
+        list.move_number(0);
+        assert_eq!(from_zero(&list), vec![0, 4, 2, 1, -3, 3, -2]);
+        let mut list = MixingList::new(parse_numbers(EXAMPLE).unwrap());
+        list.mix();
+        assert_eq!(from_zero(&list), vec![0, 3, -2, 1, 2, -3, 4]);
+    }
+
+    #[test]
+    fn duplicates() {
+        // the same value more than once, and numbers that go all the way round
+        let mut list = MixingList::new(vec![3, 0, 3, -7, 1, 1]);
+        list.mix();
+        let mut naive = vec![(0, 3), (1, 0), (2, 3), (3, -7), (4, 1), (5, 1)];
+        for index in 0..naive.len() {
+            let position = naive.iter().position(|&(i, _)| i == index).unwrap();
+            let number = naive.remove(position);
+            let new_position = (position as i64 + number.1).rem_euclid(naive.len() as i64);
+            naive.insert(new_position as usize, number);
+        }
+        let mut expected: Vec<i64> = naive.iter().map(|&(_, n)| n).collect();
+        let zero = expected.iter().position(|&n| n == 0).unwrap();
+        expected.rotate_left(zero);
+        assert_eq!(from_zero(&list), expected);
+    }
+
+    #[test]
+    fn big_key() {
+        let mut params = Params::default();
+        params.set("decryption_key", u64::MAX / 2);
+        assert!(GrovePositioningSystem.part_two(EXAMPLE, &params).is_err());
+        // big enough that the numbers only just fit, so moving them and
+        // adding up the coordinates has to take care not to overflow
+        params.set("decryption_key", i64::MAX as u64 / 4);
+        params.set("part2_rounds", 1);
+        assert!(GrovePositioningSystem.part_two(EXAMPLE, &params).is_ok());
+    }
+}
@@ -2,7 +2,7 @@ use std::collections::HashMap;
 
 use priority_queue::DoublePriorityQueue;
 
-use crate::DailyProblem;
+use crate::{DailyProblem, Error, Example, Params, Result};
 
 pub struct HillClimbingAlgorithm;
 
@@ -13,16 +13,33 @@ impl DailyProblem for HillClimbingAlgorithm {
     fn index(&self) -> u8 {
         12
     }
-    fn solutions(&self, input: &str) -> (String, String) {
-        //println!("{:?}", );
-        (
-            dijkstra(heights(input), true).to_string(),
-            dijkstra(heights(input), false).to_string(),
-        )
+    fn part_one(&self, input: &str, _params: &Params) -> Result<String> {
+        Ok(dijkstra(heights(input)?, true)
+            .ok_or_else(|| Error::no_solution("the end can't be reached"))?
+            .to_string())
+    }
+    fn part_two(&self, input: &str, _params: &Params) -> Result<String> {
+        Ok(dijkstra(heights(input)?, false)
+            .ok_or_else(|| Error::no_solution("the end can't be reached"))?
+            .to_string())
+    }
+    fn example(&self) -> Option<Example> {
+        Some(Example {
+            input: EXAMPLE,
+            part_one: "31",
+            part_two: "29",
+            params: &[],
+        })
     }
 }
 
-fn dijkstra(hts: Heights, part_1: bool) -> u32 {
+const EXAMPLE: &str = "Sabqponm
+abcryxxl
+accszExk
+acctuvwj
+abdefghi";
+
+pub fn dijkstra(hts: Heights, part_1: bool) -> Option<u32> {
     let mut unvisited_nodes_pqueue: DoublePriorityQueue<Position, u32> = DoublePriorityQueue::new();
     let mut visited_nodes: HashMap<Position, u32> = HashMap::new();
     if part_1 {
@@ -38,9 +55,9 @@ fn dijkstra(hts: Heights, part_1: bool) -> u32 {
     }
 
     loop {
-        let closest = unvisited_nodes_pqueue.pop_min().unwrap();
+        let closest = unvisited_nodes_pqueue.pop_min()?;
         if closest.0 == hts.end_position {
-            return closest.1;
+            return Some(closest.1);
         };
         visited_nodes.insert(closest.0, closest.1);
         let closest_cost = closest.1;
@@ -75,7 +92,7 @@ fn dijkstra(hts: Heights, part_1: bool) -> u32 {
 type Position = (usize, usize);
 
 #[derive(Debug)]
-struct Heights {
+pub struct Heights {
     heights: Vec<Vec<u32>>,
     paths: Vec<Vec<Path>>,
     start_position: Position,
@@ -91,37 +108,46 @@ impl Heights {
     }
 }
 
-fn heights(input: &str) -> Heights {
-    let mut start_position = (0, 0);
-    let mut end_position = (0, 0);
+pub fn heights(input: &str) -> Result<Heights> {
+    let mut start_position = None;
+    let mut end_position = None;
 
-    let mut hts = vec![];
+    let mut hts: Vec<Vec<u32>> = vec![];
     // let mut line_num = 0;
     for (line_num, line) in input.lines().enumerate() {
+        let parse_error = || Error::parse(line_num + 1, line);
+        if line.is_empty() || hts.first().is_some_and(|first| first.len() != line.len()) {
+            return Err(parse_error());
+        }
         hts.push(vec![]);
         // let mut char_num = 0;
         for (char_num, char) in line.chars().enumerate() {
             hts.get_mut(line_num).unwrap().push(match char {
                 'S' => {
-                    start_position = (line_num, char_num);
+                    start_position = Some((line_num, char_num));
                     0
                 }
                 'E' => {
-                    end_position = (line_num, char_num);
+                    end_position = Some((line_num, char_num));
                     25
                 }
-                c => c as u32 - 'a' as u32,
+                c @ 'a'..='z' => c as u32 - 'a' as u32,
+                _ => return Err(parse_error()),
             });
         }
     }
     // (c as u32) - ('a' as i32)}).collect())
+    let (start_position, end_position) = match (start_position, end_position) {
+        (Some(start_position), Some(end_position)) => (start_position, end_position),
+        _ => return Err(Error::no_solution("the map needs both a start and an end")),
+    };
     let pts = paths(&hts);
-    Heights {
+    Ok(Heights {
         heights: hts,
         paths: pts,
         start_position,
         end_position,
-    }
+    })
 }
 
 #[derive(Clone, Debug)]
@@ -145,9 +171,9 @@ impl Path {
     }
 }
 
-fn paths(hts: &Vec<Vec<u32>>) -> Vec<Vec<Path>> {
+fn paths(hts: &[Vec<u32>]) -> Vec<Vec<Path>> {
     let num_rows = hts.len();
-    let num_cols = hts.get(0).unwrap().len();
+    let num_cols = hts.first().unwrap().len();
     let mut paths = vec![vec![Path::default(); num_cols]; num_rows];
     for row in 0..num_rows {
         for col in 0..num_cols {
@@ -178,7 +204,7 @@ fn paths(hts: &Vec<Vec<u32>>) -> Vec<Vec<Path>> {
 
 #[cfg(test)]
 mod tests {
-    use crate::hill_climbing_algorithm::{dijkstra, heights};
+    use crate::days::hill_climbing_algorithm::{dijkstra, heights};
 
     #[test]
     fn t() {
@@ -187,7 +213,7 @@ abcryxxl
 accszExk
 acctuvwj
 abdefghi";
-        assert_eq!(dijkstra(heights(input), true), 31);
-        assert_eq!(dijkstra(heights(input), false), 29);
+        assert_eq!(dijkstra(heights(input).unwrap(), true), Some(31));
+        assert_eq!(dijkstra(heights(input).unwrap(), false), Some(29));
     }
 }
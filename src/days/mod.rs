@@ -0,0 +1,26 @@
+// one line per day, in any order, keyed on each problem's index()
+crate::register_days! {
+    calorie_counting::CalorieCounting,
+    rock_paper_scissors::RockPaperScissors,
+    rucksack_reorganization::RucksackReorganization,
+    camp_cleanup::CampCleanup,
+    supply_stacks::SupplyStacks,
+    tuning_trouble::TuningTrouble,
+    no_space_left_on_device::NoSpaceLeftOnDevice,
+    treetop_tree_house::TreetopTreeHouse,
+    rope_bridge::RopeBridge,
+    cathode_ray_tube::CathodeRayTube,
+    monkey_in_the_middle::MonkeyInTheMiddle,
+    hill_climbing_algorithm::HillClimbingAlgorithm,
+    distress_signal::DistressSignal,
+    regolith_reservoir::RegolithReservoir,
+    beacon_exclusion_zone::BeaconExclusionZone,
+    proboscidea_volcanium::ProboscideaVolcanium,
+    pyroclastic_flow::PyroclasticFlow,
+    boiling_boulders::BoilingBoulders,
+    not_enough_minerals::NotEnoughMinerals,
+    grove_positioning_system::GrovePositioningSystem,
+    monkey_math::MonkeyMath,
+    monkey_map::MonkeyMap,
+    unstable_diffusion::UnstableDiffusion,
+}
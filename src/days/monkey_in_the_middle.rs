@@ -0,0 +1,412 @@
+use crate::{parse_blocks, DailyProblem, Error, Example, Param, Params, Result, Simulation};
+
+pub struct MonkeyInTheMiddle;
+
+const PART_1_ROUNDS: Param = Param {
+    name: "part1_rounds",
+    default: 20,
+    description: "how many rounds the monkeys play in part 1",
+};
+const PART_2_ROUNDS: Param = Param {
+    name: "part2_rounds",
+    default: 10000,
+    description: "how many rounds the monkeys play in part 2",
+};
+
+impl DailyProblem for MonkeyInTheMiddle {
+    fn name(&self) -> &str {
+        "Day 11: Monkey in the Middle"
+    }
+    fn index(&self) -> u8 {
+        11
+    }
+    fn params(&self) -> &[Param] {
+        &[PART_1_ROUNDS, PART_2_ROUNDS]
+    }
+    fn part_one(&self, input: &str, params: &Params) -> Result<String> {
+        Ok(monkey_business(input, params.get_as(&PART_1_ROUNDS)?, true)?.to_string())
+    }
+    fn part_two(&self, input: &str, params: &Params) -> Result<String> {
+        Ok(monkey_business(input, params.get_as(&PART_2_ROUNDS)?, false)?.to_string())
+    }
+    fn example(&self) -> Option<Example> {
+        Some(Example {
+            input: EXAMPLE,
+            part_one: "10605",
+            part_two: "2713310158",
+            params: &[],
+        })
+    }
+    fn simulation<'a>(
+        &self,
+        input: &'a str,
+        part: u8,
+        params: &Params,
+    ) -> Option<Result<Box<dyn Simulation + 'a>>> {
+        let (rounds, worry_drop) = match part {
+            1 => (&PART_1_ROUNDS, true),
+            _ => (&PART_2_ROUNDS, false),
+        };
+        Some(Monkeys::new(input, worry_drop).and_then(|monkeys| {
+            Ok(Box::new(MonkeyGame {
+                monkeys,
+                rounds: 0,
+                total_rounds: params.get_as(rounds)?,
+            }) as Box<dyn Simulation>)
+        }))
+    }
+}
+
+const EXAMPLE: &str = "Monkey 0:
+  Starting items: 79, 98
+  Operation: new = old * 19
+  Test: divisible by 23
+    If true: throw to monkey 2
+    If false: throw to monkey 3
+
+Monkey 1:
+  Starting items: 54, 65, 75, 74
+  Operation: new = old + 6
+  Test: divisible by 19
+    If true: throw to monkey 2
+    If false: throw to monkey 0
+
+Monkey 2:
+  Starting items: 79, 60, 97
+  Operation: new = old * old
+  Test: divisible by 13
+    If true: throw to monkey 1
+    If false: throw to monkey 3
+
+Monkey 3:
+  Starting items: 74
+  Operation: new = old + 3
+  Test: divisible by 17
+    If true: throw to monkey 0
+    If false: throw to monkey 1";
+
+pub fn monkey_business(input: &str, num_rounds: u32, worry_drop: bool) -> Result<u64> {
+    let mut monkeys = Monkeys::new(input, worry_drop)?;
+    if monkeys.monkeys.len() < 2 {
+        return Err(Error::no_solution("there are fewer than two monkeys"));
+    }
+    (0..num_rounds).for_each(|_| monkeys.round());
+    Ok(monkeys.monkey_business())
+}
+
+// the monkeys playing round by round, for the repl
+struct MonkeyGame {
+    monkeys: Monkeys,
+    rounds: u32,
+    total_rounds: u32,
+}
+
+impl Simulation for MonkeyGame {
+    fn step(&mut self) -> Result<bool> {
+        if self.rounds < self.total_rounds {
+            self.monkeys.round();
+            self.rounds += 1;
+        }
+        Ok(self.rounds == self.total_rounds)
+    }
+
+    fn render(&self) -> String {
+        self.monkeys.as_string()
+    }
+
+    fn counters(&self) -> Vec<(String, String)> {
+        let mut counters = vec![(
+            "round".to_string(),
+            format!("{} of {}", self.rounds, self.total_rounds),
+        )];
+        for m in &self.monkeys.monkeys {
+            counters.push((
+                format!("monkey {} inspections", m.index),
+                m.inspection_count.to_string(),
+            ));
+        }
+        counters.push((
+            "monkey business".to_string(),
+            self.monkeys.monkey_business().to_string(),
+        ));
+        counters
+    }
+}
+
+pub struct Monkeys {
+    monkeys: Vec<Monkey>,
+}
+
+impl Monkeys {
+    pub fn new(input: &str, worry_drop: bool) -> Result<Self> {
+        let monkey_count = input.split("\n\n").count();
+        let mut monkeys: Vec<Monkey> = parse_blocks(input, |m| {
+            Monkey::new(m, worry_drop).filter(|monkey| {
+                monkey.test_divisible_by > 0
+                    && monkey.throw_to_if_true < monkey_count
+                    && monkey.throw_to_if_false < monkey_count
+            })
+        })?;
+        let worry_factor = monkeys.iter().map(|m| m.test_divisible_by).product();
+        monkeys
+            .iter_mut()
+            .for_each(|m| m.worry_factor = worry_factor);
+        Ok(Self { monkeys })
+    }
+
+    pub fn round(&mut self) {
+        let mut to_throw_list: Vec<Vec<u64>> = vec![];
+        for _ in 0..self.monkeys.len() {
+            to_throw_list.push(vec![]);
+        }
+        for i in 0..to_throw_list.len() {
+            let m = self.monkeys.get_mut(i).unwrap();
+            let items_to_throw = m.take_turn();
+            for (item, m_i) in items_to_throw {
+                self.monkeys.get_mut(m_i).unwrap().items.push(item);
+            }
+        }
+        /*for m in self.monkeys.iter_mut() {
+            for (item, to_throw_to) in m.take_turn() {
+                to_throw_list.get_mut(to_throw_to).unwrap().push(item);
+            }
+        }*/
+        for i in 0..to_throw_list.len() {
+            for item in to_throw_list.get(i).unwrap() {
+                self.monkeys.get_mut(i).unwrap().items.push(*item);
+            }
+        }
+    }
+
+    // the two busiest monkeys' inspection counts multiplied together
+    pub fn monkey_business(&self) -> u64 {
+        let mut inspections: Vec<u64> = self.monkeys.iter().map(|m| m.inspection_count).collect();
+        inspections.sort();
+        inspections.iter().rev().take(2).product()
+    }
+
+    // the items each monkey holds, the way the puzzle text shows them
+    pub fn as_string(&self) -> String {
+        self.monkeys
+            .iter()
+            .map(|m| {
+                let items: Vec<String> = m.items.iter().map(|i| i.to_string()).collect();
+                format!("Monkey {}: {}\n", m.index, items.join(", "))
+            })
+            .collect()
+    }
+}
+
+#[derive(Debug, PartialEq)]
+struct Monkey {
+    index: usize,
+    items: Vec<u64>,
+    operation: Operation,
+    test_divisible_by: u64,
+    throw_to_if_true: usize,
+    throw_to_if_false: usize,
+    inspection_count: u64,
+    worry_drop: bool,
+    worry_factor: u64,
+}
+
+impl Monkey {
+    fn new(input: &str, worry_drop: bool) -> Option<Self> {
+        let mut lines = input.lines();
+        let index = lines
+            .next()?
+            .replace(':', "")
+            .split(' ')
+            .next_back()?
+            .parse()
+            .ok()?;
+        let items_line = lines.next()?.trim().replace(',', "");
+        let mut item_chunks = items_line.split(' ');
+        item_chunks.nth(1);
+        let mut items = vec![];
+        for i in item_chunks {
+            items.push(i.parse().ok()?);
+        }
+        let operation = Operation::new(lines.next()?)?;
+        let test_divisible_by = lines.next()?.trim().split(' ').next_back()?.parse().ok()?;
+        let throw_to_if_true = lines.next()?.trim().split(' ').next_back()?.parse().ok()?;
+        let throw_to_if_false = lines.next()?.trim().split(' ').next_back()?.parse().ok()?;
+        Some(Self {
+            index,
+            items,
+            operation,
+            test_divisible_by,
+            throw_to_if_true,
+            throw_to_if_false,
+            inspection_count: 0,
+            worry_drop,
+            worry_factor: 0,
+        })
+    }
+
+    fn take_turn(&mut self) -> Vec<(u64, usize)> {
+        let mut items_to_throw = vec![];
+        self.items.iter().for_each(|i| {
+            items_to_throw.push(self.throw_item(i));
+        });
+        self.inspection_count += self.items.len() as u64;
+        self.items.clear();
+        items_to_throw
+    }
+
+    fn throw_item(&self, i: &u64) -> (u64, usize) {
+        let mut item = *i;
+        item = match &self.operation {
+            Operation::Add(to_add) => item + to_add,
+            Operation::AddSelf => item * 2,
+            Operation::Multiply(to_mul) => item * to_mul,
+            Operation::MultiplySelf => item * item,
+        };
+        if self.worry_drop {
+            item /= 3;
+        } else {
+            item %= self.worry_factor;
+        }
+
+        (
+            item,
+            match item.is_multiple_of(self.test_divisible_by) {
+                true => self.throw_to_if_true,
+                false => self.throw_to_if_false,
+            },
+        )
+    }
+}
+
+#[derive(Debug, PartialEq)]
+enum Operation {
+    Add(u64),
+    AddSelf,
+    Multiply(u64),
+    MultiplySelf,
+}
+
+impl Operation {
+    fn new(input: &str) -> Option<Self> {
+        let mut words = input.trim().split(' ');
+        match words.nth(4)? {
+            "*" => match words.next()? {
+                "old" => Some(Operation::MultiplySelf),
+                num => Some(Operation::Multiply(num.parse().ok()?)),
+            },
+            "+" => match words.next()? {
+                "old" => Some(Operation::AddSelf),
+                num => Some(Operation::Add(num.parse().ok()?)),
+            },
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::days::monkey_in_the_middle::{Monkey, Operation};
+
+    use super::Monkeys;
+
+    #[test]
+    fn operation() {
+        assert_eq!(
+            Operation::new("  Operation: new = old * 3"),
+            Some(Operation::Multiply(3))
+        );
+        assert_eq!(
+            Operation::new("  Operation: new = old * old"),
+            Some(Operation::MultiplySelf)
+        );
+        assert_eq!(
+            Operation::new("  Operation: new = old + 3"),
+            Some(Operation::Add(3))
+        );
+        assert_eq!(
+            Operation::new("  Operation: new = old + old"),
+            Some(Operation::AddSelf)
+        );
+    }
+
+    #[test]
+    fn monkey() {
+        let input = "Monkey 0:
+  Starting items: 84, 72, 58, 51
+  Operation: new = old * 3
+  Test: divisible by 13
+    If true: throw to monkey 1
+    If false: throw to monkey 7";
+        assert_eq!(
+            Monkey::new(input, false),
+            Some(Monkey {
+                index: 0,
+                items: vec![84, 72, 58, 51],
+                operation: Operation::Multiply(3),
+                test_divisible_by: 13,
+                throw_to_if_true: 1,
+                throw_to_if_false: 7,
+                inspection_count: 0,
+                worry_drop: false,
+                worry_factor: 0,
+            })
+        );
+    }
+
+    #[test]
+    fn rounds() {
+        let input = "Monkey 0:
+  Starting items: 79, 98
+  Operation: new = old * 19
+  Test: divisible by 23
+    If true: throw to monkey 2
+    If false: throw to monkey 3
+
+Monkey 1:
+  Starting items: 54, 65, 75, 74
+  Operation: new = old + 6
+  Test: divisible by 19
+    If true: throw to monkey 2
+    If false: throw to monkey 0
+
+Monkey 2:
+  Starting items: 79, 60, 97
+  Operation: new = old * old
+  Test: divisible by 13
+    If true: throw to monkey 1
+    If false: throw to monkey 3
+
+Monkey 3:
+  Starting items: 74
+  Operation: new = old + 3
+  Test: divisible by 17
+    If true: throw to monkey 0
+    If false: throw to monkey 1
+";
+        let mut monkeys = Monkeys::new(input, true).unwrap();
+        monkeys.round();
+        assert_eq!(monkeys.monkeys.first().unwrap().items, vec![20, 23, 27, 26]);
+        assert_eq!(
+            monkeys.monkeys.get(1).unwrap().items,
+            vec![2080, 25, 167, 207, 401, 1046]
+        );
+        assert_eq!(monkeys.monkeys.get(2).unwrap().items, vec![]);
+        assert_eq!(monkeys.monkeys.get(3).unwrap().items, vec![]);
+
+        monkeys.round();
+        assert_eq!(
+            monkeys.monkeys.first().unwrap().items,
+            vec![695, 10, 71, 135, 350]
+        );
+        assert_eq!(
+            monkeys.monkeys.get(1).unwrap().items,
+            vec![43, 49, 58, 55, 362]
+        );
+        assert_eq!(monkeys.monkeys.get(2).unwrap().items, vec![]);
+        assert_eq!(monkeys.monkeys.get(3).unwrap().items, vec![]);
+        assert_eq!(
+            monkeys.as_string(),
+            "Monkey 0: 695, 10, 71, 135, 350\nMonkey 1: 43, 49, 58, 55, 362\nMonkey 2: \nMonkey 3: \n"
+        );
+    }
+}
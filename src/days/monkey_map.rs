@@ -0,0 +1,502 @@
+use std::collections::VecDeque;
+
+use crate::{DailyProblem, Error, Example, Params, Result};
+
+pub struct MonkeyMap;
+
+impl DailyProblem for MonkeyMap {
+    fn name(&self) -> &str {
+        "Day 22: Monkey Map"
+    }
+    fn index(&self) -> u8 {
+        22
+    }
+    fn part_one(&self, input: &str, _params: &Params) -> Result<String> {
+        let (board, path) = parse(input)?;
+        let end = board.walk(&path, |p| Ok(board.wrap_flat(p)))?;
+        Ok(end.password().to_string())
+    }
+    fn part_two(&self, input: &str, _params: &Params) -> Result<String> {
+        let (board, path) = parse(input)?;
+        let cube = Cube::fold(&board)?;
+        let end = board.walk(&path, |p| cube.wrap(p))?;
+        Ok(end.password().to_string())
+    }
+    fn example(&self) -> Option<Example> {
+        Some(Example {
+            input: EXAMPLE,
+            part_one: "6032",
+            part_two: "5031",
+            params: &[],
+        })
+    }
+}
+
+const EXAMPLE: &str = "        ...#
+        .#..
+        #...
+        ....
+...#.......#
+........#...
+..#....#....
+..........#.
+        ...#....
+        .....#..
+        .#......
+        ......#.
+
+10R5L5R10L4R5L5";
+
+// facings are numbered the way the password wants them, turning right
+// goes one up
+const RIGHT: usize = 0;
+const DOWN: usize = 1;
+const LEFT: usize = 2;
+const UP: usize = 3;
+
+// a missing blank line is reported on the line after the map, where it
+// should have been, and a bad path on the line it's on
+pub fn parse(input: &str) -> Result<(Board, Vec<Step>)> {
+    let (board, path) = input
+        .split_once("\n\n")
+        .ok_or_else(|| Error::parse(input.lines().count() + 1, ""))?;
+    let board = Board::new(board)?;
+    let path_line = board.rows.len() + 2;
+    let steps = parse_path(path.trim()).ok_or_else(|| Error::parse(path_line, path.trim()))?;
+    Ok((board, steps))
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Step {
+    Forward(usize),
+    Left,
+    Right,
+}
+
+pub fn parse_path(input: &str) -> Option<Vec<Step>> {
+    let mut steps = vec![];
+    let mut number = None;
+    for c in input.chars() {
+        if let Some(digit) = c.to_digit(10) {
+            number = Some(number.unwrap_or(0) * 10 + digit as usize);
+            continue;
+        }
+        if let Some(n) = number.take() {
+            steps.push(Step::Forward(n));
+        }
+        steps.push(match c {
+            'L' => Step::Left,
+            'R' => Step::Right,
+            _ => return None,
+        });
+    }
+    if let Some(n) = number {
+        steps.push(Step::Forward(n));
+    }
+    Some(steps)
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct Position {
+    pub row: usize,
+    pub col: usize,
+    pub facing: usize,
+}
+
+impl Position {
+    pub fn password(&self) -> usize {
+        1000 * (self.row + 1) + 4 * (self.col + 1) + self.facing
+    }
+
+    fn turned(&self, turns: usize) -> Self {
+        Self {
+            facing: (self.facing + turns) % 4,
+            ..*self
+        }
+    }
+}
+
+// the map, with short rows padded out with spaces so every tile can be
+// looked up
+pub struct Board {
+    rows: Vec<Vec<u8>>,
+    width: usize,
+}
+
+impl Board {
+    pub fn new(input: &str) -> Result<Self> {
+        let mut rows: Vec<Vec<u8>> = input.lines().map(|l| l.as_bytes().to_vec()).collect();
+        for (line, row) in rows.iter().enumerate() {
+            if row.iter().any(|t| !b" .#".contains(t)) {
+                return Err(Error::parse(line + 1, &String::from_utf8_lossy(row)));
+            }
+        }
+        let width = rows.iter().map(|r| r.len()).max().unwrap_or(0);
+        rows.iter_mut().for_each(|r| r.resize(width, b' '));
+        Ok(Self { rows, width })
+    }
+
+    // leftmost open tile of the top row, facing right
+    pub fn start(&self) -> Option<Position> {
+        let col = self.rows.first()?.iter().position(|&t| t == b'.')?;
+        Some(Position {
+            row: 0,
+            col,
+            facing: RIGHT,
+        })
+    }
+
+    fn tile(&self, row: usize, col: usize) -> u8 {
+        self.rows[row][col]
+    }
+
+    // the next tile in the way `position` is facing, if it's on the map
+    fn ahead(&self, position: Position) -> Option<Position> {
+        let Position { row, col, facing } = position;
+        let (row, col) = match facing {
+            RIGHT => (row, col + 1),
+            DOWN => (row + 1, col),
+            LEFT => (row, col.checked_sub(1)?),
+            _ => (row.checked_sub(1)?, col),
+        };
+        if row >= self.rows.len() || col >= self.width || self.tile(row, col) == b' ' {
+            return None;
+        }
+        Some(Position { row, col, facing })
+    }
+
+    // follows the path from the start, with `wrap` saying where stepping
+    // off the edge of the map leads
+    pub fn walk(
+        &self,
+        path: &[Step],
+        wrap: impl Fn(Position) -> Result<Position>,
+    ) -> Result<Position> {
+        let mut position = self
+            .start()
+            .ok_or_else(|| Error::no_solution("there's no open tile on the top row to start on"))?;
+        for step in path {
+            match step {
+                Step::Left => position = position.turned(3),
+                Step::Right => position = position.turned(1),
+                Step::Forward(n) => {
+                    for _ in 0..*n {
+                        let next = match self.ahead(position) {
+                            Some(next) => next,
+                            None => wrap(position)?,
+                        };
+                        if self.tile(next.row, next.col) == b'#' {
+                            break;
+                        }
+                        position = next;
+                    }
+                }
+            }
+        }
+        Ok(position)
+    }
+
+    // comes back in on the other side of the same row or column
+    pub fn wrap_flat(&self, position: Position) -> Position {
+        let mut back = position.turned(2);
+        while let Some(next) = self.ahead(back) {
+            back = next;
+        }
+        back.turned(2)
+    }
+}
+
+// directions and points on the cube, which sits centred on the origin with
+// its faces at -size and size. points are doubled so that the middle of
+// every tile lands on whole numbers
+type Vector = [i64; 3];
+
+fn neg(v: Vector) -> Vector {
+    [-v[0], -v[1], -v[2]]
+}
+
+fn dot(a: Vector, b: Vector) -> i64 {
+    a.iter().zip(b).map(|(a, b)| a * b).sum()
+}
+
+fn add(a: Vector, b: Vector) -> Vector {
+    [a[0] + b[0], a[1] + b[1], a[2] + b[2]]
+}
+
+fn scale(v: Vector, n: i64) -> Vector {
+    [v[0] * n, v[1] * n, v[2] * n]
+}
+
+// one square of the net, and which way it ends up pointing once the net is
+// folded: `normal` points out of the cube, `right` and `down` are the ways
+// the map's columns and rows run across it
+#[derive(Debug, Clone, Copy)]
+struct Face {
+    net: (usize, usize),
+    normal: Vector,
+    right: Vector,
+    down: Vector,
+}
+
+impl Face {
+    fn direction(&self, facing: usize) -> Vector {
+        match facing {
+            RIGHT => self.right,
+            DOWN => self.down,
+            LEFT => neg(self.right),
+            _ => neg(self.down),
+        }
+    }
+
+    // the face across the edge in `facing`'s direction, folded down over it
+    fn fold(&self, facing: usize, net: (usize, usize)) -> Self {
+        let (normal, right, down) = match facing {
+            RIGHT => (self.right, neg(self.normal), self.down),
+            DOWN => (self.down, self.right, neg(self.normal)),
+            LEFT => (neg(self.right), self.normal, self.down),
+            _ => (neg(self.down), self.right, self.normal),
+        };
+        Self {
+            net,
+            normal,
+            right,
+            down,
+        }
+    }
+}
+
+// the map folded up, worked out from whatever shape the net is
+pub struct Cube {
+    size: usize,
+    faces: Vec<Face>,
+}
+
+impl Cube {
+    pub fn fold(board: &Board) -> Result<Self> {
+        let not_a_cube = || Error::no_solution("the map doesn't fold up into a cube");
+        let tiles = board.rows.iter().flatten().filter(|&&t| t != b' ').count();
+        let size = ((tiles / 6) as f64).sqrt() as usize;
+        if size == 0 || size * size * 6 != tiles {
+            return Err(not_a_cube());
+        }
+        let net_rows = board.rows.len().div_ceil(size);
+        let net_cols = board.width.div_ceil(size);
+        let in_net = |(row, col): (usize, usize)| {
+            row < net_rows && col < net_cols && board.tile(row * size, col * size) != b' '
+        };
+
+        // starting from any face, fold out across the net
+        let first = (0..net_cols).map(|col| (0, col)).find(|&n| in_net(n));
+        let first = first.ok_or_else(not_a_cube)?;
+        let mut faces = vec![Face {
+            net: first,
+            normal: [0, 0, 1],
+            right: [1, 0, 0],
+            down: [0, 1, 0],
+        }];
+        let mut queue = VecDeque::from([faces[0]]);
+        while let Some(face) = queue.pop_front() {
+            let (row, col) = face.net;
+            let neighbours = [
+                (RIGHT, Some((row, col + 1))),
+                (DOWN, Some((row + 1, col))),
+                (LEFT, col.checked_sub(1).map(|col| (row, col))),
+                (UP, row.checked_sub(1).map(|row| (row, col))),
+            ];
+            for (facing, net) in neighbours {
+                let Some(net) = net.filter(|&n| in_net(n)) else {
+                    continue;
+                };
+                if faces.iter().any(|f| f.net == net) {
+                    continue;
+                }
+                let next = face.fold(facing, net);
+                faces.push(next);
+                queue.push_back(next);
+            }
+        }
+        let folded_over = |a: &Face| faces.iter().filter(|b| b.normal == a.normal).count() > 1;
+        if faces.len() != 6 || faces.iter().any(folded_over) {
+            return Err(not_a_cube());
+        }
+        // faces were only found by their top left tile, so a ragged map could
+        // have a gap in one and the tiles that should fill it somewhere else
+        let filled = |face: &Face| {
+            let (row, col) = face.net;
+            (row * size..(row + 1) * size)
+                .all(|r| (col * size..(col + 1) * size).all(|c| board.tile(r, c) != b' '))
+        };
+        if !faces.iter().all(filled) {
+            return Err(not_a_cube());
+        }
+        Ok(Self { size, faces })
+    }
+
+    fn face_at(&self, net: (usize, usize)) -> Result<&Face> {
+        self.faces
+            .iter()
+            .find(|f| f.net == net)
+            .ok_or_else(|| Error::no_solution("walked off the cube's faces"))
+    }
+
+    fn face_towards(&self, normal: Vector) -> Result<&Face> {
+        self.faces
+            .iter()
+            .find(|f| f.normal == normal)
+            .ok_or_else(|| Error::no_solution("there's a side of the cube with no face"))
+    }
+
+    // stepping off the edge of one face goes round the cube's edge onto the
+    // next, so the point moves one (doubled) unit along the way it was going
+    // and one unit back into the cube, and carries on heading away from the
+    // face it left
+    pub fn wrap(&self, position: Position) -> Result<Position> {
+        let n = self.size as i64;
+        let from = self.face_at((position.row / self.size, position.col / self.size))?;
+        let row = (position.row % self.size) as i64;
+        let col = (position.col % self.size) as i64;
+        let point = [
+            scale(from.normal, n),
+            scale(from.right, 2 * col + 1 - n),
+            scale(from.down, 2 * row + 1 - n),
+        ]
+        .into_iter()
+        .fold([0; 3], add);
+        let heading = from.direction(position.facing);
+        let point = add(add(point, heading), neg(from.normal));
+
+        let to = self.face_towards(heading)?;
+        let row = ((dot(point, to.down) + n - 1) / 2) as usize;
+        let col = ((dot(point, to.right) + n - 1) / 2) as usize;
+        let facing = (0..4)
+            .find(|&f| to.direction(f) == neg(from.normal))
+            .ok_or_else(|| Error::no_solution("the cube's faces don't meet at an edge"))?;
+        Ok(Position {
+            row: to.net.0 * self.size + row,
+            col: to.net.1 * self.size + col,
+            facing,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{parse, parse_path, Board, Cube, Position, Step, DOWN, EXAMPLE, RIGHT, UP};
+    use crate::Error;
+
+    #[test]
+    fn path() {
+        assert_eq!(
+            parse_path("10R5L").unwrap(),
+            vec![Step::Forward(10), Step::Right, Step::Forward(5), Step::Left]
+        );
+        assert!(parse_path("10X5").is_none());
+        assert_eq!(
+            parse("..#\n.#.\n\n10X5").err(),
+            Some(Error::parse(4, "10X5"))
+        );
+        assert_eq!(parse("..#\n.#.").err(), Some(Error::parse(3, "")));
+    }
+
+    #[test]
+    fn wrap() {
+        let (board, _) = parse(EXAMPLE).unwrap();
+        let cube = Cube::fold(&board).unwrap();
+        // the puzzle's example of going off the right of the face with 6 on
+        // it and coming down onto the face beneath
+        let a = Position {
+            row: 5,
+            col: 11,
+            facing: RIGHT,
+        };
+        assert_eq!(
+            cube.wrap(a),
+            Ok(Position {
+                row: 8,
+                col: 14,
+                facing: DOWN
+            })
+        );
+        assert_eq!(
+            board.wrap_flat(a),
+            Position {
+                row: 5,
+                col: 0,
+                facing: RIGHT
+            }
+        );
+        let c = Position {
+            row: 11,
+            col: 10,
+            facing: DOWN,
+        };
+        assert_eq!(
+            cube.wrap(c),
+            Ok(Position {
+                row: 7,
+                col: 1,
+                facing: UP
+            })
+        );
+    }
+
+    // a net of open tiles, one character per face
+    fn open_net(net: &str, size: usize) -> Board {
+        let rows: Vec<String> = net
+            .lines()
+            .flat_map(|line| {
+                let row: String = line
+                    .chars()
+                    .map(|c| match c {
+                        '#' => ".".repeat(size),
+                        _ => " ".repeat(size),
+                    })
+                    .collect();
+                vec![row; size]
+            })
+            .collect();
+        Board::new(&rows.join("\n")).unwrap()
+    }
+
+    #[test]
+    fn round_the_cube() {
+        // the example's net and the shape the real inputs come in, and going
+        // straight ahead from anywhere on an open cube comes back round
+        let size = 4;
+        for net in ["  #\n###\n  ##", " ##\n #\n##\n#"] {
+            let board = open_net(net, size);
+            let cube = Cube::fold(&board).unwrap();
+            for row in 0..board.rows.len() {
+                for col in 0..board.width {
+                    if board.tile(row, col) == b' ' {
+                        continue;
+                    }
+                    for facing in 0..4 {
+                        let mut position = Position { row, col, facing };
+                        for _ in 0..4 * size {
+                            position = match board.ahead(position) {
+                                Some(next) => next,
+                                None => cube.wrap(position).unwrap(),
+                            };
+                        }
+                        assert_eq!(position, Position { row, col, facing });
+                    }
+                }
+            }
+        }
+        assert!(Cube::fold(&open_net("####\n####", size)).is_err());
+        assert!(Cube::fold(&open_net("###\n###", size)).is_err());
+        // the right number of tiles, and something at the top left of each
+        // face, but one face has a gap that's been filled in elsewhere
+        let mut board = open_net("  #\n###\n  ##", size);
+        board.rows[size - 1][3 * size - 1] = b' ';
+        board.rows[1][1] = b'.';
+        assert!(Cube::fold(&board).is_err());
+    }
+
+    #[test]
+    fn nowhere_to_start() {
+        let board = Board::new("###\n...").unwrap();
+        assert!(board.walk(&[Step::Forward(1)], Ok).is_err());
+    }
+}
@@ -0,0 +1,276 @@
+use std::{collections::HashMap, fmt};
+
+use crate::{parse_lines, DailyProblem, Error, Example, Params, Result};
+
+pub struct MonkeyMath;
+
+impl DailyProblem for MonkeyMath {
+    fn name(&self) -> &str {
+        "Day 21: Monkey Math"
+    }
+    fn index(&self) -> u8 {
+        21
+    }
+    fn part_one(&self, input: &str, _params: &Params) -> Result<String> {
+        let monkeys = parse_monkeys(input)?;
+        Expression::build(&monkeys, ROOT, None)?
+            .evaluate()
+            .map(|n| n.to_string())
+            .ok_or_else(|| Error::no_solution("root's number can't be worked out"))
+    }
+    fn part_two(&self, input: &str, _params: &Params) -> Result<String> {
+        let monkeys = parse_monkeys(input)?;
+        // root is really checking that its two monkeys match
+        let Some(Job::Operation(left, _, right)) = monkeys.get(ROOT) else {
+            return Err(Error::no_solution(
+                "root doesn't wait for two other monkeys",
+            ));
+        };
+        let left = Expression::build(&monkeys, left, Some(HUMAN))?;
+        let right = Expression::build(&monkeys, right, Some(HUMAN))?;
+        left.solve_equal(&right)
+            .map(|n| n.to_string())
+            .ok_or_else(|| Error::no_solution("no number makes root's two monkeys match"))
+    }
+    fn example(&self) -> Option<Example> {
+        Some(Example {
+            input: EXAMPLE,
+            part_one: "152",
+            part_two: "301",
+            params: &[],
+        })
+    }
+}
+
+const EXAMPLE: &str = "root: pppw + sjmn
+dbpl: 5
+cczh: sllz + lgvd
+zczc: 2
+ptdq: humn - dvpt
+dvpt: 3
+lfqf: 4
+humn: 5
+ljgn: 2
+sjmn: drzm * dbpl
+sllz: 4
+pppw: cczh / lfqf
+lgvd: ljgn * ptdq
+drzm: hmdt - zczc
+hmdt: 32";
+
+const ROOT: &str = "root";
+const HUMAN: &str = "humn";
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Op {
+    Add,
+    Sub,
+    Mul,
+    Div,
+}
+
+impl Op {
+    fn new(input: &str) -> Option<Self> {
+        match input {
+            "+" => Some(Self::Add),
+            "-" => Some(Self::Sub),
+            "*" => Some(Self::Mul),
+            "/" => Some(Self::Div),
+            _ => None,
+        }
+    }
+
+    // None on overflow or a division that doesn't come out whole
+    fn apply(&self, left: i64, right: i64) -> Option<i64> {
+        match self {
+            Self::Add => left.checked_add(right),
+            Self::Sub => left.checked_sub(right),
+            Self::Mul => left.checked_mul(right),
+            Self::Div => exact_div(left, right),
+        }
+    }
+
+    fn symbol(&self) -> char {
+        match self {
+            Self::Add => '+',
+            Self::Sub => '-',
+            Self::Mul => '*',
+            Self::Div => '/',
+        }
+    }
+}
+
+fn exact_div(left: i64, right: i64) -> Option<i64> {
+    match left.checked_rem(right)? {
+        0 => left.checked_div(right),
+        _ => None,
+    }
+}
+
+// what a monkey yells, straight from the input
+#[derive(Debug, PartialEq)]
+pub enum Job {
+    Number(i64),
+    Operation(String, Op, String),
+}
+
+impl Job {
+    pub fn new(input: &str) -> Option<(String, Self)> {
+        let (name, job) = input.trim().split_once(": ")?;
+        let job = match job.split(' ').collect::<Vec<_>>()[..] {
+            [number] => Self::Number(number.parse().ok()?),
+            [left, op, right] => Self::Operation(left.to_string(), Op::new(op)?, right.to_string()),
+            _ => return None,
+        };
+        Some((name.to_string(), job))
+    }
+}
+
+pub fn parse_monkeys(input: &str) -> Result<HashMap<String, Job>> {
+    Ok(parse_lines(input, Job::new)?.into_iter().collect())
+}
+
+// a monkey's job with everything it waits on filled in. parts that don't
+// depend on the unknown are worked out as the tree is built, so at most one
+// side of any operation is left holding it
+#[derive(Debug, PartialEq)]
+pub enum Expression {
+    Number(i64),
+    Unknown,
+    Operation(Box<Expression>, Op, Box<Expression>),
+}
+
+impl Expression {
+    // the expression for `name`, with the monkey called `unknown` (if any)
+    // left as the unknown
+    pub fn build(
+        monkeys: &HashMap<String, Job>,
+        name: &str,
+        unknown: Option<&str>,
+    ) -> Result<Self> {
+        Self::build_from(monkeys, name, unknown, &mut vec![])
+    }
+
+    fn build_from<'a>(
+        monkeys: &'a HashMap<String, Job>,
+        name: &'a str,
+        unknown: Option<&str>,
+        waiting: &mut Vec<&'a str>,
+    ) -> Result<Self> {
+        if unknown == Some(name) {
+            return Ok(Self::Unknown);
+        }
+        if waiting.contains(&name) {
+            return Err(Error::no_solution(&format!(
+                "{} ends up waiting on itself",
+                name
+            )));
+        }
+        let job = monkeys
+            .get(name)
+            .ok_or_else(|| Error::no_solution(&format!("there is no monkey called {}", name)))?;
+        Ok(match job {
+            Job::Number(n) => Self::Number(*n),
+            Job::Operation(left, op, right) => {
+                waiting.push(name);
+                let left = Self::build_from(monkeys, left, unknown, waiting)?;
+                let right = Self::build_from(monkeys, right, unknown, waiting)?;
+                waiting.pop();
+                let operation = Self::Operation(Box::new(left), *op, Box::new(right));
+                match operation.evaluate() {
+                    Some(n) => Self::Number(n),
+                    None => operation,
+                }
+            }
+        })
+    }
+
+    // None if it depends on the unknown, or the arithmetic doesn't work out
+    pub fn evaluate(&self) -> Option<i64> {
+        match self {
+            Self::Number(n) => Some(*n),
+            Self::Unknown => None,
+            Self::Operation(left, op, right) => op.apply(left.evaluate()?, right.evaluate()?),
+        }
+    }
+
+    // the value of the unknown that makes this come out as `target`, found
+    // by undoing each operation on the way down to it
+    pub fn solve(&self, target: i64) -> Option<i64> {
+        match self {
+            Self::Number(_) => None,
+            Self::Unknown => Some(target),
+            Self::Operation(left, op, right) => match (left.evaluate(), right.evaluate()) {
+                (Some(left), None) => right.solve(match op {
+                    Op::Add => target.checked_sub(left)?,
+                    Op::Sub => left.checked_sub(target)?,
+                    Op::Mul => exact_div(target, left)?,
+                    Op::Div => exact_div(left, target)?,
+                }),
+                (None, Some(right)) => left.solve(match op {
+                    Op::Add => target.checked_sub(right)?,
+                    Op::Sub => target.checked_add(right)?,
+                    Op::Mul => exact_div(target, right)?,
+                    Op::Div => target.checked_mul(right)?,
+                }),
+                _ => None,
+            },
+        }
+    }
+
+    // the value of the unknown that makes both sides equal
+    pub fn solve_equal(&self, other: &Self) -> Option<i64> {
+        match (self.evaluate(), other.evaluate()) {
+            (Some(n), None) => other.solve(n),
+            (None, Some(n)) => self.solve(n),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for Expression {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Number(n) => write!(f, "{}", n),
+            Self::Unknown => write!(f, "x"),
+            Self::Operation(left, op, right) => write!(f, "({} {} {})", left, op.symbol(), right),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{parse_monkeys, Expression, Job, Op, EXAMPLE};
+
+    #[test]
+    fn job() {
+        assert_eq!(
+            Job::new("dbpl: 5"),
+            Some(("dbpl".to_string(), Job::Number(5)))
+        );
+        assert_eq!(
+            Job::new("root: pppw + sjmn"),
+            Some((
+                "root".to_string(),
+                Job::Operation("pppw".to_string(), Op::Add, "sjmn".to_string())
+            ))
+        );
+        assert_eq!(Job::new("root: pppw % sjmn"), None);
+        assert_eq!(Job::new("dbpl 5"), None);
+    }
+
+    #[test]
+    fn build() {
+        let monkeys = parse_monkeys(EXAMPLE).unwrap();
+        let pppw = Expression::build(&monkeys, "pppw", Some("humn")).unwrap();
+        assert_eq!(pppw.to_string(), "((4 + (2 * (x - 3))) / 4)");
+        assert_eq!(pppw.evaluate(), None);
+        let sjmn = Expression::build(&monkeys, "sjmn", Some("humn")).unwrap();
+        assert_eq!(sjmn, Expression::Number(150));
+        assert_eq!(pppw.solve(150), Some(301));
+        assert_eq!(sjmn.solve_equal(&pppw), Some(301));
+        assert!(Expression::build(&monkeys, "nobody", None).is_err());
+        let looped = parse_monkeys("root: a + b\na: b * b\nb: a - a").unwrap();
+        assert!(Expression::build(&looped, "root", None).is_err());
+    }
+}
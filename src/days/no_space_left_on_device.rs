@@ -0,0 +1,165 @@
+use std::collections::HashMap;
+
+use crate::{DailyProblem, Error, Example, Param, Params, Result};
+
+pub struct NoSpaceLeftOnDevice;
+
+const DISK_SIZE: Param = Param {
+    name: "disk_size",
+    default: 70000000,
+    description: "the total size of the filesystem",
+};
+const SPACE_NEEDED: Param = Param {
+    name: "space_needed",
+    default: 30000000,
+    description: "the free space the update needs",
+};
+
+impl DailyProblem for NoSpaceLeftOnDevice {
+    fn name(&self) -> &str {
+        "Day 7: No Space Left On Device"
+    }
+    fn index(&self) -> u8 {
+        7
+    }
+    fn params(&self) -> &[Param] {
+        &[DISK_SIZE, SPACE_NEEDED]
+    }
+    fn part_one(&self, input: &str, _params: &Params) -> Result<String> {
+        Ok(directories_under(&parse_tree(input)?, 100000).to_string())
+    }
+    fn part_two(&self, input: &str, params: &Params) -> Result<String> {
+        Ok(directory_to_delete(
+            &parse_tree(input)?,
+            params.get_as(&DISK_SIZE)?,
+            params.get_as(&SPACE_NEEDED)?,
+        )
+        .ok_or_else(|| Error::no_solution("no directory is big enough to delete"))?
+        .to_string())
+    }
+    fn example(&self) -> Option<Example> {
+        Some(Example {
+            input: EXAMPLE,
+            part_one: "95437",
+            part_two: "24933642",
+            params: &[],
+        })
+    }
+}
+
+const EXAMPLE: &str = "$ cd /
+$ ls
+dir a
+14848514 b.txt
+8504156 c.dat
+dir d
+$ cd a
+$ ls
+dir e
+29116 f
+2557 g
+62596 h.lst
+$ cd e
+$ ls
+584 i
+$ cd ..
+$ cd ..
+$ cd d
+$ ls
+4060174 j
+8033020 d.log
+5626152 d.ext
+7214296 k";
+
+pub fn parse_tree(input: &str) -> Result<HashMap<String, u32>> {
+    let mut directories: HashMap<String, u32> = HashMap::new();
+    directories.insert("".to_string(), 0);
+    let mut context = vec![""];
+    for (line_index, line) in input.lines().enumerate() {
+        let parse_error = || Error::parse(line_index + 1, line);
+        match InputLine::new(line).ok_or_else(parse_error)? {
+            InputLine::CdRoot => {
+                context.clear();
+                context.push("");
+            }
+            InputLine::CdDir(name) => {
+                context.push(name);
+            }
+            InputLine::CdUp => {
+                if context.len() > 1 {
+                    context.pop();
+                }
+            }
+            InputLine::Ls => {}
+            InputLine::File(size) => {
+                let dir = directories
+                    .get_mut(&context.join("/"))
+                    .ok_or_else(parse_error)?;
+                *dir += size;
+                for index in 0..context.len() - 1 {
+                    let dir = directories
+                        .get_mut(&context[0..=index].join("/"))
+                        .ok_or_else(parse_error)?;
+                    *dir += size;
+                }
+            }
+            InputLine::Dir(name) => {
+                let mut path = context.join("/");
+                path.push('/');
+                path.push_str(name);
+                directories.insert(path, 0);
+            }
+        }
+    }
+    Ok(directories)
+}
+
+pub fn directories_under(dirs: &HashMap<String, u32>, size_limit: u32) -> u32 {
+    dirs.iter()
+        .filter(|d| *d.1 <= size_limit)
+        .map(|d| d.1)
+        .sum()
+}
+
+pub fn directory_to_delete(
+    dirs: &HashMap<String, u32>,
+    total_size: u32,
+    target_size: u32,
+) -> Option<u32> {
+    let free_space = total_size.checked_sub(*dirs.get("").unwrap())?;
+    let space_to_delete = target_size.saturating_sub(free_space);
+    dirs.iter()
+        .filter(|d| *d.1 >= space_to_delete)
+        .map(|d| d.1)
+        .min()
+        .copied()
+}
+
+#[derive(Debug)]
+enum InputLine<'a> {
+    CdRoot,
+    Ls,
+    File(u32),
+    Dir(&'a str),
+    CdUp,
+    CdDir(&'a str),
+}
+
+impl<'a> InputLine<'a> {
+    fn new(input: &'a str) -> Option<Self> {
+        let mut words = input.split(' ');
+        match words.next()? {
+            "$" => match words.next()? {
+                "cd" => match words.next()? {
+                    ".." => Some(InputLine::CdUp),
+                    "/" => Some(InputLine::CdRoot),
+                    dir_name => Some(InputLine::CdDir(dir_name)),
+                },
+                "ls" => Some(InputLine::Ls),
+                _ => None,
+            },
+            "dir" => Some(InputLine::Dir(words.next()?)),
+            file_size => Some(InputLine::File(file_size.parse().ok()?)),
+        }
+    }
+}
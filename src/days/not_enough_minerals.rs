@@ -0,0 +1,210 @@
+use lazy_static::lazy_static;
+use regex::Regex;
+
+use crate::{parse_lines, DailyProblem, Example, Param, Params, Result};
+
+pub struct NotEnoughMinerals;
+
+const PART_1_MINUTES: Param = Param {
+    name: "part1_minutes",
+    default: 24,
+    description: "how long the robots have in part 1",
+};
+const PART_2_MINUTES: Param = Param {
+    name: "part2_minutes",
+    default: 32,
+    description: "how long the robots have in part 2",
+};
+const PART_2_BLUEPRINTS: Param = Param {
+    name: "part2_blueprints",
+    default: 3,
+    description: "how many blueprints are left uneaten in part 2",
+};
+
+impl DailyProblem for NotEnoughMinerals {
+    fn name(&self) -> &str {
+        "Day 19: Not Enough Minerals"
+    }
+    fn index(&self) -> u8 {
+        19
+    }
+    fn params(&self) -> &[Param] {
+        &[PART_1_MINUTES, PART_2_MINUTES, PART_2_BLUEPRINTS]
+    }
+    fn part_one(&self, input: &str, params: &Params) -> Result<String> {
+        let minutes = params.get_as(&PART_1_MINUTES)?;
+        Ok(parse_lines(input, Blueprint::new)?
+            .iter()
+            .map(|b| b.quality_level(minutes))
+            .sum::<u32>()
+            .to_string())
+    }
+    fn part_two(&self, input: &str, params: &Params) -> Result<String> {
+        let minutes = params.get_as(&PART_2_MINUTES)?;
+        Ok(parse_lines(input, Blueprint::new)?
+            .iter()
+            .take(params.get_as(&PART_2_BLUEPRINTS)?)
+            .map(|b| b.max_geodes(minutes))
+            .product::<u32>()
+            .to_string())
+    }
+    fn example(&self) -> Option<Example> {
+        Some(Example {
+            input: EXAMPLE,
+            part_one: "33",
+            part_two: "3472",
+            params: &[],
+        })
+    }
+}
+
+// the puzzle text wraps these, the real input has one per line
+const EXAMPLE: &str = "Blueprint 1: Each ore robot costs 4 ore. Each clay robot costs 2 ore. Each obsidian robot costs 3 ore and 14 clay. Each geode robot costs 2 ore and 7 obsidian.
+Blueprint 2: Each ore robot costs 2 ore. Each clay robot costs 3 ore. Each obsidian robot costs 3 ore and 8 clay. Each geode robot costs 3 ore and 12 obsidian.";
+
+// resources and robots are both indexed by what they collect
+const ORE: usize = 0;
+const CLAY: usize = 1;
+const OBSIDIAN: usize = 2;
+const GEODE: usize = 3;
+
+#[derive(Debug, PartialEq)]
+pub struct Blueprint {
+    pub id: u32,
+    // costs[robot][resource], nothing costs geodes
+    pub costs: [[u32; 3]; 4],
+}
+
+impl Blueprint {
+    pub fn new(input: &str) -> Option<Self> {
+        lazy_static! {
+            static ref RE: Regex = Regex::new(
+                "Blueprint (\\d+): Each ore robot costs (\\d+) ore\\. Each clay robot costs (\\d+) ore\\. Each obsidian robot costs (\\d+) ore and (\\d+) clay\\. Each geode robot costs (\\d+) ore and (\\d+) obsidian\\."
+            )
+            .unwrap();
+        }
+        let caps = RE.captures(input)?;
+        let n = |i: usize| caps.get(i)?.as_str().parse::<u32>().ok();
+        Some(Self {
+            id: n(1)?,
+            costs: [
+                [n(2)?, 0, 0],
+                [n(3)?, 0, 0],
+                [n(4)?, n(5)?, 0],
+                [n(6)?, 0, n(7)?],
+            ],
+        })
+    }
+
+    pub fn quality_level(&self, minutes: u32) -> u32 {
+        self.id * self.max_geodes(minutes)
+    }
+
+    pub fn max_geodes(&self, minutes: u32) -> u32 {
+        // there's no use having more of a robot than can be spent in a minute,
+        // since only one robot is built at a time
+        let mut most_useful = [u32::MAX; 4];
+        for resource in [ORE, CLAY, OBSIDIAN] {
+            most_useful[resource] = self.costs.iter().map(|c| c[resource]).max().unwrap_or(0);
+        }
+        let mut best = 0;
+        self.search(
+            State {
+                minutes_left: minutes,
+                robots: [1, 0, 0, 0],
+                resources: [0; 4],
+            },
+            &most_useful,
+            &mut best,
+        );
+        best
+    }
+
+    // rather than deciding what to do every minute, each branch picks the
+    // next robot to build and skips ahead to when it can be afforded
+    fn search(&self, state: State, most_useful: &[u32; 4], best: &mut u32) {
+        let t = state.minutes_left;
+        let geodes = state.resources[GEODE] + state.robots[GEODE] * t;
+        *best = (*best).max(geodes);
+        // even a new geode robot every remaining minute can't beat the best
+        if geodes + t * t.saturating_sub(1) / 2 <= *best {
+            return;
+        }
+        for robot in [GEODE, OBSIDIAN, CLAY, ORE] {
+            if state.robots[robot] >= most_useful[robot] {
+                continue;
+            }
+            if let Some(next) = state.build(robot, &self.costs[robot]) {
+                self.search(next, most_useful, best);
+            }
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+struct State {
+    minutes_left: u32,
+    robots: [u32; 4],
+    resources: [u32; 4],
+}
+
+impl State {
+    // waits until the robot can be afforded and builds it, None if that
+    // can't happen with a minute to spare for it to collect anything
+    fn build(&self, robot: usize, cost: &[u32; 3]) -> Option<Self> {
+        let mut wait = 0;
+        for (resource, &needed) in cost.iter().enumerate() {
+            let missing = needed.saturating_sub(self.resources[resource]);
+            if missing > 0 {
+                let rate = self.robots[resource];
+                if rate == 0 {
+                    return None;
+                }
+                wait = wait.max(missing.div_ceil(rate));
+            }
+        }
+        let elapsed = wait + 1;
+        if elapsed >= self.minutes_left {
+            return None;
+        }
+        let mut next = *self;
+        next.minutes_left -= elapsed;
+        for resource in 0..4 {
+            next.resources[resource] += self.robots[resource] * elapsed;
+        }
+        for (resource, &spent) in cost.iter().enumerate() {
+            next.resources[resource] -= spent;
+        }
+        next.robots[robot] += 1;
+        Some(next)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Blueprint, EXAMPLE};
+
+    #[test]
+    fn blueprint() {
+        let blueprint = Blueprint::new(EXAMPLE.lines().next().unwrap()).unwrap();
+        assert_eq!(
+            blueprint,
+            Blueprint {
+                id: 1,
+                costs: [[4, 0, 0], [2, 0, 0], [3, 14, 0], [2, 0, 7]],
+            }
+        );
+        assert_eq!(
+            Blueprint::new("Blueprint 1: Each ore robot costs 4 ore."),
+            None
+        );
+    }
+
+    #[test]
+    fn max_geodes() {
+        let blueprints: Vec<Blueprint> = EXAMPLE.lines().filter_map(Blueprint::new).collect();
+        assert_eq!(blueprints[0].max_geodes(24), 9);
+        assert_eq!(blueprints[1].max_geodes(24), 12);
+        assert_eq!(blueprints[1].quality_level(24), 24);
+    }
+}
@@ -0,0 +1,279 @@
+use std::cmp::Reverse;
+use std::collections::{hash_map::Entry, HashMap, VecDeque};
+
+use crate::{parse_lines, DailyProblem, Error, Example, Params, Result};
+use lazy_static::lazy_static;
+use regex::Regex;
+
+pub struct ProboscideaVolcanium;
+
+impl DailyProblem for ProboscideaVolcanium {
+    fn name(&self) -> &str {
+        "Day 16: Proboscidea Volcanium"
+    }
+    fn index(&self) -> u8 {
+        16
+    }
+    fn part_one(&self, input: &str, _params: &Params) -> Result<String> {
+        Ok(most_pressure_released(&Network::new(input)?, 30).to_string())
+    }
+    fn part_two(&self, input: &str, _params: &Params) -> Result<String> {
+        Ok(most_pressure_released_with_elephant(&Network::new(input)?, 26).to_string())
+    }
+    fn example(&self) -> Option<Example> {
+        Some(Example {
+            input: EXAMPLE,
+            part_one: "1651",
+            part_two: "1707",
+            params: &[],
+        })
+    }
+}
+
+const EXAMPLE: &str = "Valve AA has flow rate=0; tunnels lead to valves DD, II, BB
+Valve BB has flow rate=13; tunnels lead to valves CC, AA
+Valve CC has flow rate=2; tunnels lead to valves DD, BB
+Valve DD has flow rate=20; tunnels lead to valves CC, AA, EE
+Valve EE has flow rate=3; tunnels lead to valves FF, DD
+Valve FF has flow rate=0; tunnels lead to valves EE, GG
+Valve GG has flow rate=0; tunnels lead to valves FF, HH
+Valve HH has flow rate=22; tunnel leads to valve GG
+Valve II has flow rate=0; tunnels lead to valves AA, JJ
+Valve JJ has flow rate=21; tunnel leads to valve II";
+
+// only the valves with a nonzero flow rate are worth walking to,
+// so the network collapses into a complete graph between those
+// valves (plus AA, where we start), with edge weights being the
+// shortest path through the tunnels.
+// each of those valves gets a bit, and the search records the best
+// pressure it managed for every set of opened valves; part 2 is then
+// just the best pair of disjoint sets, one for you and one for the elephant
+
+pub fn most_pressure_released(network: &Network, minutes: u32) -> u32 {
+    *network.best_per_valve_set(minutes).values().max().unwrap()
+}
+
+pub fn most_pressure_released_with_elephant(network: &Network, minutes: u32) -> u32 {
+    let best = network.best_per_valve_set(minutes);
+    let mut best: Vec<(u64, u32)> = best.into_iter().collect();
+    best.sort_by_key(|b| Reverse(b.1));
+    let mut most = 0;
+    for (i, (my_valves, my_pressure)) in best.iter().enumerate() {
+        if my_pressure * 2 < most {
+            break;
+        }
+        for (elephant_valves, elephant_pressure) in &best[i..] {
+            if my_pressure + elephant_pressure <= most {
+                break;
+            }
+            if my_valves & elephant_valves == 0 {
+                most = my_pressure + elephant_pressure;
+            }
+        }
+    }
+    most
+}
+
+#[derive(Debug, PartialEq)]
+struct Valve {
+    name: String,
+    flow_rate: u32,
+    tunnels: Vec<String>,
+}
+
+impl Valve {
+    fn new(input: &str) -> Option<Self> {
+        lazy_static! {
+            static ref RE: Regex =
+                Regex::new("Valve (\\w+) has flow rate=(\\d+); tunnels? leads? to valves? (.*)")
+                    .unwrap();
+        }
+        let caps = RE.captures(input)?;
+        Some(Self {
+            name: caps.get(1)?.as_str().to_string(),
+            flow_rate: caps.get(2)?.as_str().parse().ok()?,
+            tunnels: caps
+                .get(3)?
+                .as_str()
+                .split(", ")
+                .map(|t| t.to_string())
+                .collect(),
+        })
+    }
+}
+
+#[derive(Debug)]
+pub struct Network {
+    // flow rates of the valves worth opening, the start valve is last
+    flow_rates: Vec<u32>,
+    // distances[a][b] is the number of minutes it takes to walk from a to b
+    distances: Vec<Vec<u32>>,
+}
+
+const START_VALVE: &str = "AA";
+
+impl Network {
+    pub fn new(input: &str) -> Result<Self> {
+        let valves: Vec<Valve> = parse_lines(input, Valve::new)?;
+        let valve_indices: HashMap<&str, usize> = valves
+            .iter()
+            .enumerate()
+            .map(|(i, v)| (v.name.as_str(), i))
+            .collect();
+        for (line, (valve, text)) in valves.iter().zip(input.lines()).enumerate() {
+            if valve
+                .tunnels
+                .iter()
+                .any(|t| !valve_indices.contains_key(t.as_str()))
+            {
+                return Err(Error::parse(line + 1, text));
+            }
+        }
+
+        let mut interesting_valves: Vec<usize> = valves
+            .iter()
+            .enumerate()
+            .filter(|(_, v)| v.flow_rate > 0)
+            .map(|(i, _)| i)
+            .collect();
+        if interesting_valves.len() >= u64::BITS as usize {
+            return Err(Error::no_solution(
+                "there are too many valves worth opening",
+            ));
+        }
+        interesting_valves.push(
+            *valve_indices
+                .get(START_VALVE)
+                .ok_or_else(|| Error::no_solution(&format!("there is no valve {}", START_VALVE)))?,
+        );
+
+        let distances = interesting_valves
+            .iter()
+            .map(|from| {
+                let all_distances = shortest_paths(&valves, &valve_indices, *from);
+                interesting_valves
+                    .iter()
+                    .map(|to| *all_distances.get(to).unwrap_or(&u32::MAX))
+                    .collect()
+            })
+            .collect();
+
+        Ok(Self {
+            flow_rates: interesting_valves
+                .iter()
+                .map(|i| valves.get(*i).unwrap().flow_rate)
+                .collect(),
+            distances,
+        })
+    }
+
+    fn start(&self) -> usize {
+        self.flow_rates.len() - 1
+    }
+
+    fn best_per_valve_set(&self, minutes: u32) -> HashMap<u64, u32> {
+        let mut best = HashMap::new();
+        self.search(self.start(), minutes, 0, 0, &mut best);
+        best
+    }
+
+    fn search(
+        &self,
+        position: usize,
+        minutes_left: u32,
+        opened: u64,
+        released: u32,
+        best: &mut HashMap<u64, u32>,
+    ) {
+        let entry = best.entry(opened).or_insert(0);
+        if released > *entry {
+            *entry = released;
+        }
+        for next in 0..self.start() {
+            if opened & (1 << next) != 0 {
+                continue;
+            }
+            let cost = self
+                .distances
+                .get(position)
+                .unwrap()
+                .get(next)
+                .unwrap()
+                .saturating_add(1);
+            if cost >= minutes_left {
+                continue;
+            }
+            let minutes_left = minutes_left - cost;
+            self.search(
+                next,
+                minutes_left,
+                opened | (1 << next),
+                released + minutes_left * self.flow_rates.get(next).unwrap(),
+                best,
+            );
+        }
+    }
+}
+
+fn shortest_paths(
+    valves: &[Valve],
+    valve_indices: &HashMap<&str, usize>,
+    from: usize,
+) -> HashMap<usize, u32> {
+    let mut distances = HashMap::new();
+    distances.insert(from, 0);
+    let mut queue = VecDeque::new();
+    queue.push_back(from);
+    while let Some(current) = queue.pop_front() {
+        let distance = *distances.get(&current).unwrap();
+        for tunnel in &valves.get(current).unwrap().tunnels {
+            let next = *valve_indices.get(tunnel.as_str()).unwrap();
+            if let Entry::Vacant(e) = distances.entry(next) {
+                e.insert(distance + 1);
+                queue.push_back(next);
+            }
+        }
+    }
+    distances
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        most_pressure_released, most_pressure_released_with_elephant, Network, Valve, EXAMPLE,
+    };
+
+    #[test]
+    fn valve() {
+        assert_eq!(
+            Valve::new("Valve HH has flow rate=22; tunnel leads to valve GG"),
+            Some(Valve {
+                name: "HH".to_string(),
+                flow_rate: 22,
+                tunnels: vec!["GG".to_string()],
+            })
+        );
+        assert_eq!(
+            Valve::new("Valve AA has flow rate=0; tunnels lead to valves DD, II, BB")
+                .unwrap()
+                .tunnels,
+            vec!["DD".to_string(), "II".to_string(), "BB".to_string()]
+        );
+    }
+
+    #[test]
+    fn distances() {
+        let network = Network::new(EXAMPLE).unwrap();
+        // BB, CC, DD, EE, HH, JJ, AA
+        assert_eq!(network.flow_rates, vec![13, 2, 20, 3, 22, 21, 0]);
+        assert_eq!(network.distances[6], vec![1, 2, 1, 2, 5, 2, 0]);
+        assert_eq!(network.distances[4][5], 7);
+    }
+
+    #[test]
+    fn pressure() {
+        let network = Network::new(EXAMPLE).unwrap();
+        assert_eq!(most_pressure_released(&network, 30), 1651);
+        assert_eq!(most_pressure_released_with_elephant(&network, 26), 1707);
+    }
+}
@@ -1,9 +1,20 @@
-use std::collections::HashSet;
+use std::collections::{HashMap, HashSet};
 
-use crate::DailyProblem;
+use crate::{DailyProblem, Error, Example, Param, Params, Result, Simulation};
 
 pub struct PyroclasticFlow;
 
+const PART_1_PIECES: Param = Param {
+    name: "part1_pieces",
+    default: 2022,
+    description: "how many rocks fall in part 1",
+};
+const PART_2_PIECES: Param = Param {
+    name: "part2_pieces",
+    default: 1_000_000_000_000,
+    description: "how many rocks fall in part 2",
+};
+
 impl DailyProblem for PyroclasticFlow {
     fn name(&self) -> &str {
         "Day 17: Pyroclastic Flow"
@@ -11,72 +22,187 @@ impl DailyProblem for PyroclasticFlow {
     fn index(&self) -> u8 {
         17
     }
-    fn solutions(&self, input: &str) -> (String, String) {
-        (
-            highest_rock_after_x_pieces(input, 2022).to_string(),
-            (-1).to_string(),
-        )
+    fn params(&self) -> &[Param] {
+        &[PART_1_PIECES, PART_2_PIECES]
+    }
+    fn part_one(&self, input: &str, params: &Params) -> Result<String> {
+        Ok(Cave::new(input)?
+            .height_after(params.get(&PART_1_PIECES))
+            .to_string())
+    }
+    fn part_two(&self, input: &str, params: &Params) -> Result<String> {
+        Ok(Cave::new(input)?
+            .height_after(params.get(&PART_2_PIECES))
+            .to_string())
+    }
+    fn example(&self) -> Option<Example> {
+        Some(Example {
+            input: EXAMPLE,
+            part_one: "3068",
+            part_two: "1514285714288",
+            params: &[],
+        })
+    }
+    fn simulation<'a>(
+        &self,
+        input: &'a str,
+        part: u8,
+        params: &Params,
+    ) -> Option<Result<Box<dyn Simulation + 'a>>> {
+        let pieces = match part {
+            1 => params.get(&PART_1_PIECES),
+            _ => params.get(&PART_2_PIECES),
+        };
+        Some(Cave::new(input).map(|mut cave| {
+            cave.spawn_piece();
+            Box::new(RockFall { cave, pieces }) as Box<dyn Simulation>
+        }))
     }
 }
 
-fn highest_rock_after_x_pieces(input: &str, pieces: u32) -> usize {
-    let mut cave = Cave::new(input);
-    cave.spawn_piece();
-    while cave.dropped_pieces <= pieces {
-        cave.step();
+// rocks falling one jet of gas at a time, for the repl
+struct RockFall {
+    cave: Cave,
+    pieces: u64,
+}
+
+// more than this and the bottom of the tower is left out of the drawing
+const DRAWN_ROWS: usize = 40;
+
+impl Simulation for RockFall {
+    // a step is one push from a jet, and then one fall or coming to rest
+    fn step(&mut self) -> Result<bool> {
+        if self.cave.landed_pieces() < self.pieces {
+            self.cave.step();
+        }
+        Ok(self.cave.landed_pieces() >= self.pieces)
+    }
+
+    fn render(&self) -> String {
+        let drawing = self.cave.as_string();
+        let rows = drawing.lines().count();
+        let mut s: String = drawing
+            .lines()
+            .take(DRAWN_ROWS)
+            .map(|line| format!("{}\n", line))
+            .collect();
+        if rows > DRAWN_ROWS {
+            s.push_str(&format!("({} rows further down)\n", rows - DRAWN_ROWS));
+        }
+        s
+    }
+
+    fn counters(&self) -> Vec<(String, String)> {
+        vec![
+            (
+                "rocks landed".to_string(),
+                format!("{} of {}", self.cave.landed_pieces(), self.pieces),
+            ),
+            (
+                "tower height".to_string(),
+                self.cave.tower_height().to_string(),
+            ),
+            (
+                "next jet".to_string(),
+                (self.cave.jets.index % self.cave.jets.jet_directions.len()).to_string(),
+            ),
+        ]
     }
-    cave.highest_rock()
 }
 
+const EXAMPLE: &str = ">>><<><>><<<>><>>><<<>>><<<><<<>><>><<>>";
+
 #[derive(Debug, PartialEq, Eq, Hash)]
 struct Position(usize, usize);
 
 #[derive(Debug)]
-struct Cave {
+pub struct Cave {
     cells: Vec<Vec<bool>>,
     pieces: PieceTemplateIterator,
     jets: JetIterator,
     active_piece: Option<Piece>,
-    dropped_pieces: u32,
+    dropped_pieces: u64,
+    highest_rock: usize,
+}
+
+// the next piece, the next jet and the shape of the top of the tower
+// are all that matter for how the tower grows from here on, so once
+// we see the same state twice the pieces in between repeat forever
+#[derive(Debug, PartialEq, Eq, Hash)]
+struct CaveState {
+    piece_index: usize,
+    jet_index: usize,
+    surface: [usize; CAVE_WIDTH],
 }
 
 const CAVE_WIDTH: usize = 7;
 
 impl Cave {
-    fn new(input: &str) -> Self {
-        Self {
+    pub fn new(input: &str) -> Result<Self> {
+        Ok(Self {
             cells: vec![vec![true; CAVE_WIDTH]],
             pieces: PieceTemplateIterator::new(PIECE_TEMPLATE_STRING),
-            jets: JetIterator::new(input),
+            jets: JetIterator::new(input)?,
             active_piece: None,
             dropped_pieces: 0,
+            highest_rock: 1,
+        })
+    }
+
+    pub fn height_after(&mut self, pieces: u64) -> u64 {
+        let mut seen_states = HashMap::new();
+        let mut skipped_height = 0;
+        self.spawn_piece();
+        while self.landed_pieces() < pieces {
+            self.drop_piece();
+            if skipped_height > 0 {
+                continue;
+            }
+            let landed = (self.landed_pieces(), self.tower_height() as u64);
+            if let Some((previous_pieces, previous_height)) =
+                seen_states.insert(self.state(), landed)
+            {
+                let cycle_pieces = landed.0 - previous_pieces;
+                let cycles = (pieces - landed.0) / cycle_pieces;
+                skipped_height = cycles * (landed.1 - previous_height);
+                self.dropped_pieces += cycles * cycle_pieces;
+            }
         }
+        self.tower_height() as u64 + skipped_height
     }
 
-    fn height(&self) -> usize {
-        self.cells.len()
+    pub fn landed_pieces(&self) -> u64 {
+        // the active piece has already been counted
+        self.dropped_pieces - 1
     }
 
-    fn highest_rock(&self) -> usize {
-        let mut row_index = 0;
-        while !self.is_empty_row(row_index) {
-            row_index += 1;
-        }
-        row_index
+    pub fn tower_height(&self) -> usize {
+        // row 0 is the floor
+        self.highest_rock - 1
     }
 
-    fn is_empty_row(&self, row_index: usize) -> bool {
-        //dbg!("rows: {}, row_index: {}", self.cells.len(), row_index);
-        if row_index > 0 && self.cells.len() < row_index + 1 {
-            return true;
+    fn state(&self) -> CaveState {
+        let mut surface = [0; CAVE_WIDTH];
+        for (col_index, depth) in surface.iter_mut().enumerate() {
+            let mut row_index = self.highest_rock - 1;
+            while !self.cells.get(row_index).unwrap().get(col_index).unwrap() {
+                row_index -= 1;
+            }
+            *depth = self.highest_rock - row_index;
+        }
+        CaveState {
+            piece_index: self.pieces.index % self.pieces.piece_templates.len(),
+            jet_index: self.jets.index % self.jets.jet_directions.len(),
+            surface,
         }
-        self.cells
-            .get(row_index)
-            .unwrap()
-            .iter()
-            .filter(|c| **c)
-            .count()
-            == 0
+    }
+
+    fn height(&self) -> usize {
+        self.cells.len()
+    }
+
+    fn highest_rock(&self) -> usize {
+        self.highest_rock
     }
 
     fn add_row(&mut self) {
@@ -95,7 +221,8 @@ impl Cave {
         // println!("{}", self.as_string());
     }
 
-    fn _as_string(&self) -> String {
+    // * marks the corner the falling rock is placed by, o the rest of it
+    pub fn as_string(&self) -> String {
         let mut active_piece_cells = HashSet::new();
         let active_piece = self.active_piece.as_ref().unwrap();
         for cell in active_piece.cells() {
@@ -125,6 +252,13 @@ impl Cave {
         s
     }
 
+    pub fn drop_piece(&mut self) {
+        let dropped_pieces = self.dropped_pieces;
+        while self.dropped_pieces == dropped_pieces {
+            self.step();
+        }
+    }
+
     fn step(&mut self) {
         let jet = self.jets.next().unwrap();
         self.move_active_piece_laterally(jet);
@@ -175,6 +309,9 @@ impl Cave {
         for cell in self.active_piece.as_ref().unwrap().cells() {
             self.cells.get_mut(cell.0).unwrap().push(true);
             self.cells.get_mut(cell.0).unwrap().swap_remove(cell.1);
+            if cell.0 >= self.highest_rock {
+                self.highest_rock = cell.0 + 1;
+            }
         }
         self.spawn_piece();
     }
@@ -193,19 +330,13 @@ impl Cave {
     }
 
     fn piece_intersects_with_rock(&self, piece: &Piece) -> bool {
-        let mut active_piece_cells = HashSet::new();
-        for cell in piece.cells() {
-            active_piece_cells.insert(cell);
-        }
-        // println!("piece to check: {:?}", &piece);
-        for (row_index, row) in self.cells.iter().enumerate() {
-            for (col_index, cell) in row.iter().enumerate() {
-                if *cell && active_piece_cells.contains(&Position(row_index, col_index)) {
-                    return true;
-                }
-            }
-        }
-        false
+        piece
+            .cells()
+            .iter()
+            .any(|cell| match self.cells.get(cell.0) {
+                Some(row) => *row.get(cell.1).unwrap(),
+                None => false,
+            })
     }
 }
 
@@ -268,19 +399,23 @@ struct JetIterator {
 }
 
 impl JetIterator {
-    fn new(input: &str) -> Self {
+    fn new(input: &str) -> Result<Self> {
+        let line = input.lines().next().unwrap_or("");
         let mut jet_directions = vec![];
-        for c in input.lines().next().unwrap().chars() {
+        for c in line.chars() {
             jet_directions.push(match c {
                 '>' => JetDirection::Right,
                 '<' => JetDirection::Left,
-                invalid_char => panic!("Invalid jet direction {}", invalid_char),
+                _ => return Err(Error::parse(1, line)),
             });
         }
-        Self {
+        if jet_directions.is_empty() {
+            return Err(Error::parse(1, line));
+        }
+        Ok(Self {
             jet_directions,
             index: 0,
-        }
+        })
     }
 }
 
@@ -315,7 +450,7 @@ impl PieceTemplate {
             }
         }
         // let height = cells.len();
-        let width = cells.get(0).unwrap().len();
+        let width = cells.first().unwrap().len();
         Self {
             cells,
             // height,
@@ -325,7 +460,7 @@ impl PieceTemplate {
 
     fn _as_string(&self) -> String {
         let mut s = "".to_string();
-        for line in self.cells.iter() {
+        for line in self.cells.iter().rev() {
             for cell in line {
                 match cell {
                     true => s.push('#'),
@@ -391,7 +526,7 @@ const PIECE_TEMPLATE_STRING: &str = "####
 
 #[cfg(test)]
 mod tests {
-    use crate::pyroclastic_flow::{JetDirection, JetIterator};
+    use crate::days::pyroclastic_flow::{JetDirection, JetIterator};
 
     use super::{Cave, PieceTemplateIterator, PIECE_TEMPLATE_STRING};
 
@@ -408,7 +543,7 @@ mod tests {
 
     #[test]
     fn jet_iterator() {
-        let mut ji = JetIterator::new("><>");
+        let mut ji = JetIterator::new("><>").unwrap();
         assert_eq!(ji.next().unwrap(), JetDirection::Right);
         assert_eq!(ji.next().unwrap(), JetDirection::Left);
         assert_eq!(ji.next().unwrap(), JetDirection::Right);
@@ -419,11 +554,24 @@ mod tests {
     #[test]
     fn run() {
         let input = ">>><<><>><<<>><>>><<<>>><<<><<<>><>><<>>";
-        let mut cave = Cave::new(input);
+        let mut cave = Cave::new(input).unwrap();
         cave.spawn_piece();
         while cave.dropped_pieces < 11 {
             cave.step();
         }
-        panic!()
+        assert_eq!(cave.tower_height(), 17);
+        assert!(cave
+            .as_string()
+            .ends_with("|..###..|\n|...#...|\n|..####.|\n|#######|\n"));
+    }
+
+    #[test]
+    fn height_after() {
+        let input = ">>><<><>><<<>><>>><<<>>><<<><<<>><>><<>>";
+        assert_eq!(Cave::new(input).unwrap().height_after(2022), 3068);
+        assert_eq!(
+            Cave::new(input).unwrap().height_after(1_000_000_000_000),
+            1514285714288
+        );
     }
 }
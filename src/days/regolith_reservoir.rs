@@ -1,6 +1,6 @@
 use std::cmp::Ordering;
 
-use crate::DailyProblem;
+use crate::{parse_lines, DailyProblem, Error, Example, Params, Result, Simulation};
 
 pub struct RegolithReservoir;
 
@@ -11,24 +11,43 @@ impl DailyProblem for RegolithReservoir {
     fn index(&self) -> u8 {
         14
     }
-    fn solutions(&self, input: &str) -> (String, String) {
-        (
-            GameState::new(input, false).run().to_string(),
-            GameState::new(input, true).run().to_string(),
-        )
+    fn part_one(&self, input: &str, _params: &Params) -> Result<String> {
+        Ok(GameState::new(input, false)?.run().to_string())
+    }
+    fn part_two(&self, input: &str, _params: &Params) -> Result<String> {
+        Ok(GameState::new(input, true)?.run().to_string())
+    }
+    fn example(&self) -> Option<Example> {
+        Some(Example {
+            input: EXAMPLE,
+            part_one: "24",
+            part_two: "93",
+            params: &[],
+        })
+    }
+    fn simulation<'a>(
+        &self,
+        input: &'a str,
+        part: u8,
+        _params: &Params,
+    ) -> Option<Result<Box<dyn Simulation + 'a>>> {
+        Some(GameState::new(input, part == 2).map(|state| Box::new(state) as Box<dyn Simulation>))
     }
 }
 
+const EXAMPLE: &str = "498,4 -> 498,6 -> 496,6
+503,4 -> 502,4 -> 502,9 -> 494,9";
+
 #[derive(Debug, Clone, PartialEq)]
 struct Position(usize, usize);
 
 impl Position {
-    fn new(input: &str) -> Self {
+    fn new(input: &str) -> Option<Self> {
         let mut coords = input.split(',');
-        Self(
-            coords.next().unwrap().parse().unwrap(),
-            coords.next().unwrap().parse().unwrap(),
-        )
+        Some(Self(
+            coords.next()?.parse().ok()?,
+            coords.next()?.parse().ok()?,
+        ))
     }
 }
 
@@ -36,10 +55,14 @@ impl Position {
 struct Path(Vec<Position>);
 
 impl Path {
-    fn new(input: &str) -> Self {
+    fn new(input: &str) -> Option<Self> {
         let mut all_positions = vec![];
-        let mut positions = input.split(" -> ").map(Position::new);
-        let mut position = positions.next().unwrap();
+        let mut positions = input
+            .split(" -> ")
+            .map(Position::new)
+            .collect::<Option<Vec<Position>>>()?
+            .into_iter();
+        let mut position = positions.next()?;
         all_positions.push(position.clone());
         for next_position in positions {
             while position != next_position {
@@ -61,7 +84,7 @@ impl Path {
                 all_positions.push(position.clone());
             }
         }
-        Self(all_positions)
+        Some(Self(all_positions))
         // Self(positions.map(Position::new).collect())
     }
 }
@@ -70,8 +93,8 @@ impl Path {
 struct Paths(Vec<Path>);
 
 impl Paths {
-    fn new(input: &str) -> Self {
-        Paths(input.lines().map(Path::new).collect())
+    fn new(input: &str) -> Result<Self> {
+        Ok(Paths(parse_lines(input, Path::new)?))
     }
 }
 
@@ -83,7 +106,7 @@ enum Cell {
 }
 
 #[derive(Debug)]
-struct GameState {
+pub struct GameState {
     cells: Vec<Vec<Cell>>,
     active_sand_position: Position,
     sand_units: u32,
@@ -93,8 +116,8 @@ struct GameState {
 }
 
 impl GameState {
-    fn new(input: &str, has_floor: bool) -> Self {
-        let paths = Paths::new(input);
+    pub fn new(input: &str, has_floor: bool) -> Result<Self> {
+        let paths = Paths::new(input)?;
         let mut max_x = 0;
         let mut max_y = 0;
         for path in &paths.0 {
@@ -110,17 +133,28 @@ impl GameState {
             }
         }
 
+        // sand spreads out at most one column either way for each row it
+        // falls, and the floor is two below the lowest rock, so the grid
+        // has to reach that far either side of x=500 as well as the rock
+        let spread = max_y + 3;
+        if spread > 500 {
+            return Err(Error::no_solution(
+                "the rock goes too deep for the sand to stay right of x=0",
+            ));
+        }
+        let width = max_x.max(500 + spread) + 1;
+
         let mut cells = vec![];
         for _ in 0..=max_y + 1 {
             cells.push(vec![]);
-            for _ in 0..=max_x * 2 {
+            for _ in 0..width {
                 cells.last_mut().unwrap().push(Cell::Air);
             }
         }
 
         if has_floor {
             cells.push(vec![]);
-            for _ in 0..=max_x * 2 {
+            for _ in 0..width {
                 cells.last_mut().unwrap().push(Cell::Rock);
             }
         }
@@ -135,30 +169,39 @@ impl GameState {
         cells.get_mut(0).unwrap().push(Cell::Sand);
         cells.get_mut(0).unwrap().swap_remove(500);
 
-        Self {
+        Ok(Self {
             cells,
             active_sand_position: Position(0, 500),
             sand_units: 0,
             max_y,
             has_floor,
             entrance_blocked: false,
-        }
+        })
     }
 }
 
 impl GameState {
-    fn _print(&self) {
+    // only the columns with something in them, and one either side
+    pub fn as_string(&self) -> String {
+        let used_columns = self.cells[..=self.max_y + 1]
+            .iter()
+            .flat_map(|row| row.iter().enumerate())
+            .filter(|(_, cell)| !matches!(cell, Cell::Air))
+            .map(|(col, _)| col);
+        let first = used_columns.clone().min().unwrap_or(500).saturating_sub(1);
+        let last = used_columns.max().unwrap_or(500) + 1;
+        let mut s = String::new();
         for row in &self.cells {
-            for cell in &row[450..550] {
-                match cell {
-                    Cell::Air => print!("."),
-                    Cell::Rock => print!("#"),
-                    Cell::Sand => print!("o"),
-                }
+            for cell in &row[first..=last.min(row.len() - 1)] {
+                s.push(match cell {
+                    Cell::Air => '.',
+                    Cell::Rock => '#',
+                    Cell::Sand => 'o',
+                });
             }
-            println!();
+            s.push('\n');
         }
-        println!("max y: {}", self.max_y);
+        s
     }
 
     fn set_cell(&mut self, row: usize, col: usize, cell: Cell) {
@@ -170,7 +213,7 @@ impl GameState {
         self.cells.get(row).unwrap().get(col).unwrap()
     }
 
-    fn step(&mut self) {
+    pub fn step(&mut self) {
         if let Cell::Air =
             self.get_cell(self.active_sand_position.0 + 1, self.active_sand_position.1)
         {
@@ -234,7 +277,7 @@ impl GameState {
         }
     }
 
-    fn game_over(&self) -> bool {
+    pub fn game_over(&self) -> bool {
         if self.has_floor {
             self.entrance_blocked
         } else {
@@ -242,7 +285,7 @@ impl GameState {
         }
     }
 
-    fn run(&mut self) -> u32 {
+    pub fn run(&mut self) -> u32 {
         while !self.game_over() {
             self.step();
         }
@@ -250,6 +293,34 @@ impl GameState {
     }
 }
 
+impl Simulation for GameState {
+    // a step is the falling sand moving one cell, or coming to rest
+    fn step(&mut self) -> Result<bool> {
+        if !self.game_over() {
+            GameState::step(self);
+        }
+        Ok(self.game_over())
+    }
+
+    fn render(&self) -> String {
+        self.as_string()
+    }
+
+    fn counters(&self) -> Vec<(String, String)> {
+        vec![
+            ("sand at rest".to_string(), self.sand_units.to_string()),
+            (
+                "falling sand".to_string(),
+                format!(
+                    "{},{}",
+                    self.active_sand_position.1, self.active_sand_position.0
+                ),
+            ),
+            ("game over".to_string(), self.game_over().to_string()),
+        ]
+    }
+}
+
 impl Iterator for GameState {
     type Item = ();
 
@@ -264,18 +335,37 @@ impl Iterator for GameState {
 
 #[cfg(test)]
 mod tests {
-    use crate::regolith_reservoir::GameState;
+    use crate::days::regolith_reservoir::GameState;
 
     #[test]
     fn init() {
         let input = "498,4 -> 498,6 -> 496,6
 503,4 -> 502,4 -> 502,9 -> 494,9";
-        let mut gs = GameState::new(input, false);
+        let mut gs = GameState::new(input, false).unwrap();
         assert_eq!(gs.run(), 24);
-        let mut gs = GameState::new(input, true);
-        gs.run();
-        gs._print();
-
+        let mut gs = GameState::new(input, true).unwrap();
         assert_eq!(gs.run(), 93);
+        let drawing = gs.as_string();
+        assert_eq!(drawing.lines().count(), 12);
+        assert_eq!(drawing.lines().nth(9), Some("..ooo#########ooooooo.."));
+    }
+
+    #[test]
+    fn narrow_cave() {
+        // all the rock is left of where the sand comes in
+        let mut gs = GameState::new("10,4 -> 12,4", false).unwrap();
+        assert_eq!(gs.run(), 0);
+        let mut gs = GameState::new("10,4 -> 12,4", true).unwrap();
+        assert_eq!(gs.run(), 36);
+        assert!(GameState::new("10,600 -> 12,600", true).is_err());
+    }
+
+    #[test]
+    fn no_rock() {
+        let mut gs = GameState::new("", false).unwrap();
+        assert_eq!(gs.run(), 0);
+        // the floor is at y=2, so the sand piles up two rows high
+        let mut gs = GameState::new("", true).unwrap();
+        assert_eq!(gs.run(), 4);
     }
 }
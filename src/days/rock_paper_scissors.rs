@@ -1,4 +1,4 @@
-use aoc_2022::{sum_lines, DailyProblem};
+use crate::{sum_lines, DailyProblem, Example, Params, Result};
 use std::str::FromStr;
 
 pub struct RockPaperScissors;
@@ -10,30 +10,42 @@ impl DailyProblem for RockPaperScissors {
     fn index(&self) -> u8 {
         2
     }
-    fn solutions(&self, input: &str) -> (String, String) {
-        (
-            sum_lines(input, score_round_part_1).to_string(),
-            sum_lines(input, score_round_part_2).to_string(),
-        )
+    fn part_one(&self, input: &str, _params: &Params) -> Result<String> {
+        Ok(sum_lines(input, score_round_part_1)?.to_string())
+    }
+    fn part_two(&self, input: &str, _params: &Params) -> Result<String> {
+        Ok(sum_lines(input, score_round_part_2)?.to_string())
+    }
+    fn example(&self) -> Option<Example> {
+        Some(Example {
+            input: EXAMPLE,
+            part_one: "15",
+            part_two: "12",
+            params: &[],
+        })
     }
 }
 
-pub fn score_round_part_1(input: &str) -> u32 {
+const EXAMPLE: &str = "A Y
+B X
+C Z";
+
+pub fn score_round_part_1(input: &str) -> Option<u32> {
     let mut signs = input.split(' ');
-    let their_sign = Sign::from_str(signs.next().unwrap()).unwrap();
-    let my_sign = Sign::from_str(signs.next().unwrap()).unwrap();
+    let their_sign = Sign::from_str(signs.next()?).ok()?;
+    let my_sign = Sign::from_str(signs.next()?).ok()?;
     let result = my_sign.result(their_sign);
 
-    my_sign.score() + result.score()
+    Some(my_sign.score() + result.score())
 }
 
-pub fn score_round_part_2(input: &str) -> u32 {
+pub fn score_round_part_2(input: &str) -> Option<u32> {
     let mut signs = input.split(' ');
-    let their_sign = Sign::from_str(signs.next().unwrap()).unwrap();
-    let result = RoundResult::from_str(signs.next().unwrap()).unwrap();
+    let their_sign = Sign::from_str(signs.next()?).ok()?;
+    let result = RoundResult::from_str(signs.next()?).ok()?;
     let my_sign = result.sign_to_throw(their_sign);
 
-    my_sign.score() + result.score()
+    Some(my_sign.score() + result.score())
 }
 
 #[derive(PartialEq)]
@@ -106,7 +118,7 @@ impl RoundResult {
 impl FromStr for RoundResult {
     type Err = ();
 
-    fn from_str(input: &str) -> Result<Self, ()> {
+    fn from_str(input: &str) -> std::result::Result<Self, ()> {
         match input {
             "X" => Ok(RoundResult::Loss),
             "Y" => Ok(RoundResult::Tie),
@@ -119,7 +131,7 @@ impl FromStr for RoundResult {
 impl FromStr for Sign {
     type Err = ();
 
-    fn from_str(input: &str) -> Result<Self, <Self as FromStr>::Err> {
+    fn from_str(input: &str) -> std::result::Result<Self, <Self as FromStr>::Err> {
         match input {
             "A" => Ok(Sign::Rock),
             "X" => Ok(Sign::Rock),
@@ -136,15 +148,15 @@ impl FromStr for Sign {
 mod tests {
     #[test]
     fn score_round_part_1() {
-        assert_eq!(super::score_round_part_1("A Y"), 8);
-        assert_eq!(super::score_round_part_1("B X"), 1);
-        assert_eq!(super::score_round_part_1("C Z"), 6);
+        assert_eq!(super::score_round_part_1("A Y"), Some(8));
+        assert_eq!(super::score_round_part_1("B X"), Some(1));
+        assert_eq!(super::score_round_part_1("C Z"), Some(6));
     }
 
     #[test]
     fn score_round_part_2() {
-        assert_eq!(super::score_round_part_2("A Y"), 4);
-        assert_eq!(super::score_round_part_2("B X"), 1);
-        assert_eq!(super::score_round_part_2("C Z"), 7);
+        assert_eq!(super::score_round_part_2("A Y"), Some(4));
+        assert_eq!(super::score_round_part_2("B X"), Some(1));
+        assert_eq!(super::score_round_part_2("C Z"), Some(7));
     }
 }
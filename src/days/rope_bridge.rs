@@ -1,6 +1,6 @@
-use std::{cmp::Ordering, collections::HashSet, str::Lines};
+use std::{cmp::Ordering, collections::HashSet, vec::IntoIter};
 
-use aoc_2022::DailyProblem;
+use crate::{parse_lines, DailyProblem, Example, Params, Result};
 
 pub struct RopeBridge;
 
@@ -11,30 +11,45 @@ impl DailyProblem for RopeBridge {
     fn index(&self) -> u8 {
         9
     }
-    fn solutions(&self, input: &str) -> (String, String) {
+    fn part_one(&self, input: &str, _params: &Params) -> Result<String> {
         let mut rope = Rope::new(2);
-        let move_iterator = MoveIterator::new(input);
+        let move_iterator = MoveIterator::new(input)?;
         move_iterator.for_each(|dir| rope.mv(dir));
-
+        Ok(rope.tail_visited.len().to_string())
+    }
+    fn part_two(&self, input: &str, _params: &Params) -> Result<String> {
         let mut long_rope = Rope::new(10);
-        let move_iterator = MoveIterator::new(input);
+        let move_iterator = MoveIterator::new(input)?;
         move_iterator.for_each(|dir| long_rope.mv(dir));
-
-        (
-            rope.tail_visited.len().to_string(),
-            long_rope.tail_visited.len().to_string(),
-        )
+        Ok(long_rope.tail_visited.len().to_string())
+    }
+    fn example(&self) -> Option<Example> {
+        Some(Example {
+            input: EXAMPLE,
+            part_one: "13",
+            part_two: "1",
+            params: &[],
+        })
     }
 }
 
+const EXAMPLE: &str = "R 4
+U 4
+L 3
+D 1
+R 4
+D 1
+L 5
+R 2";
+
 #[derive(Debug)]
-struct Rope {
+pub struct Rope {
     knots: Vec<Position>,
     tail_visited: HashSet<Position>,
 }
 
 impl Rope {
-    fn new(length: usize) -> Self {
+    pub fn new(length: usize) -> Self {
         let mut tail_visited = HashSet::new();
         tail_visited.insert(Position::default());
         let knots = vec![Position::default(); length];
@@ -44,7 +59,7 @@ impl Rope {
         }
     }
 
-    fn mv(&mut self, dir: Direction) {
+    pub fn mv(&mut self, dir: Direction) {
         self.knots.first_mut().unwrap().mv(dir);
         for knot_index in 0..self.knots.len() - 1 {
             let directions = self
@@ -133,15 +148,12 @@ impl Position {
 
 impl Clone for Position {
     fn clone(&self) -> Self {
-        Self {
-            x: self.x,
-            y: self.y,
-        }
+        *self
     }
 }
 
 #[derive(Copy, Debug, PartialEq)]
-enum Direction {
+pub enum Direction {
     Up,
     Down,
     Left,
@@ -149,7 +161,7 @@ enum Direction {
 }
 
 impl Direction {
-    fn new(input: &str) -> Result<Self, String> {
+    fn new(input: &str) -> std::result::Result<Self, String> {
         match input {
             "U" => Ok(Self::Up),
             "D" => Ok(Self::Down),
@@ -162,12 +174,7 @@ impl Direction {
 
 impl Clone for Direction {
     fn clone(&self) -> Self {
-        match self {
-            Self::Up => Self::Up,
-            Self::Down => Self::Down,
-            Self::Left => Self::Left,
-            Self::Right => Self::Right,
-        }
+        *self
     }
 }
 
@@ -177,34 +184,37 @@ struct Move {
 }
 
 impl Move {
-    fn new(input: &str) -> Self {
+    fn new(input: &str) -> Option<Self> {
         let mut spl = input.split(' ');
-        Self {
-            dir: Direction::new(spl.next().unwrap()).unwrap(),
-            times: spl.next().unwrap().parse().unwrap(),
-        }
+        Some(Self {
+            dir: Direction::new(spl.next()?).ok()?,
+            times: spl.next()?.parse().ok()?,
+        })
     }
 }
 
-struct MoveIterator<'a> {
-    lines: Lines<'a>,
+pub struct MoveIterator {
+    moves: IntoIter<Move>,
     current_move: Move,
     index_in_move: u32,
 }
 
-impl<'a> MoveIterator<'a> {
-    fn new(input: &'a str) -> Self {
-        let mut lines = input.lines();
-        let current_move = Move::new(lines.next().unwrap());
-        Self {
-            lines,
+impl MoveIterator {
+    pub fn new(input: &str) -> Result<Self> {
+        let mut moves = parse_lines(input, Move::new)?.into_iter();
+        let current_move = moves.next().unwrap_or(Move {
+            dir: Direction::Up,
+            times: 0,
+        });
+        Ok(Self {
+            moves,
             current_move,
             index_in_move: 0,
-        }
+        })
     }
 }
 
-impl<'a> Iterator for MoveIterator<'a> {
+impl Iterator for MoveIterator {
     type Item = Direction;
 
     fn next(&mut self) -> Option<Direction> {
@@ -213,9 +223,9 @@ impl<'a> Iterator for MoveIterator<'a> {
                 self.index_in_move += 1;
                 Some(self.current_move.dir)
             }
-            false => match self.lines.next() {
-                Some(line) => {
-                    self.current_move = Move::new(line);
+            false => match self.moves.next() {
+                Some(current_move) => {
+                    self.current_move = current_move;
                     self.index_in_move = 1;
                     Some(self.current_move.dir)
                 }
@@ -240,7 +250,8 @@ R 4
 D 1
 L 5
 R 2",
-        );
+        )
+        .unwrap();
         assert_eq!(mi.next().unwrap(), Direction::Right);
         assert_eq!(mi.next().unwrap(), Direction::Right);
         assert_eq!(mi.next().unwrap(), Direction::Right);
@@ -292,12 +303,12 @@ D 1
 L 5
 R 2";
         let mut rope = Rope::new(2);
-        let move_iterator = MoveIterator::new(input);
+        let move_iterator = MoveIterator::new(input).unwrap();
         move_iterator.for_each(|dir| rope.mv(dir));
         assert_eq!(rope.tail_visited.len(), 13);
 
         let mut long_rope = Rope::new(10);
-        let move_iterator = MoveIterator::new(input);
+        let move_iterator = MoveIterator::new(input).unwrap();
         move_iterator.for_each(|dir| long_rope.mv(dir));
         assert_eq!(long_rope.tail_visited.len(), 1);
 
@@ -310,7 +321,7 @@ D 10
 L 25
 U 20";
         let mut long_rope = Rope::new(10);
-        let move_iterator = MoveIterator::new(input);
+        let move_iterator = MoveIterator::new(input).unwrap();
         move_iterator.for_each(|dir| long_rope.mv(dir));
         assert_eq!(long_rope.tail_visited.len(), 36);
     }
@@ -1,4 +1,4 @@
-use aoc_2022::{sum_lines, DailyProblem};
+use crate::{sum_lines, DailyProblem, Error, Example, Params, Result};
 use std::iter::Iterator;
 use std::str::{FromStr, Lines};
 
@@ -11,19 +11,44 @@ impl DailyProblem for RucksackReorganization {
     fn index(&self) -> u8 {
         3
     }
-    fn solutions(&self, input: &str) -> (String, String) {
-        (
-            sum_lines(input, calculate_priority).to_string(),
-            ElfGroupIter(input.lines())
-                .map(|eg| eg.priority().unwrap())
-                .sum::<u32>()
-                .to_string(),
-        )
+    fn part_one(&self, input: &str, _params: &Params) -> Result<String> {
+        Ok(sum_lines(input, calculate_priority)?.to_string())
+    }
+    fn part_two(&self, input: &str, _params: &Params) -> Result<String> {
+        Ok(badge_priorities(input)?.to_string())
+    }
+    fn example(&self) -> Option<Example> {
+        Some(Example {
+            input: EXAMPLE,
+            part_one: "157",
+            part_two: "70",
+            params: &[],
+        })
     }
 }
 
-fn calculate_priority(line: &str) -> u32 {
-    Rucksack::from_str(line).unwrap().priority().unwrap()
+const EXAMPLE: &str = "vJrwpWtwJgWrhcsFMMfFFhFp
+jqHRNqRjqzjGDLGLrsFMfFZSrLrFZsSL
+PmmdzqPrVvPwwTWBwg
+wMqvLMZHhHMvwLHjbvcjnnSBnvTQFn
+ttgJtRGJQctTZtZT
+CrZsJsPPZsGzwwsLwLmpwMDw";
+
+pub fn calculate_priority(line: &str) -> Option<u32> {
+    Rucksack::from_str(line).ok()?.priority().ok()
+}
+
+pub fn badge_priorities(input: &str) -> Result<u32> {
+    let mut sum = 0;
+    for (index, elf_group) in ElfGroupIter(input.lines()).enumerate() {
+        sum += elf_group.priority().map_err(|_| {
+            Error::parse(
+                index * 3 + 1,
+                &[elf_group.0.as_str(), &elf_group.1, &elf_group.2].join("\n"),
+            )
+        })?;
+    }
+    Ok(sum)
 }
 
 struct ElfGroupIter<'a>(Lines<'a>);
@@ -66,7 +91,7 @@ impl ElfGroup {
             _ => Some(badge_candidates.chars().next().unwrap()),
         }
     }
-    fn priority(&self) -> Result<u32, String> {
+    fn priority(&self) -> std::result::Result<u32, String> {
         match self.badge_letter() {
             Some(letter) => letter_priority(letter),
             None => Err(format!(
@@ -82,7 +107,7 @@ struct Rucksack(String, String, String);
 impl FromStr for Rucksack {
     type Err = ();
 
-    fn from_str(input: &str) -> Result<Self, ()> {
+    fn from_str(input: &str) -> std::result::Result<Self, ()> {
         match input.len() % 2 == 1 {
             true => Err(()),
             false => Ok(Self(
@@ -94,7 +119,7 @@ impl FromStr for Rucksack {
     }
 }
 
-fn letter_priority(input_char: char) -> Result<u32, String> {
+fn letter_priority(input_char: char) -> std::result::Result<u32, String> {
     let input = input_char as u32;
     if input > 64 && input < 91 {
         Ok(input - (65 - 27))
@@ -113,7 +138,7 @@ impl Rucksack {
         self.1.chars().find(|c| self.2.contains(&(c.to_string())))
     }
 
-    fn priority(&self) -> Result<u32, String> {
+    fn priority(&self) -> std::result::Result<u32, String> {
         match self.common_letter_between_pockets() {
             Some(letter) => letter_priority(letter),
             None => Err(format!(
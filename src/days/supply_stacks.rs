@@ -0,0 +1,280 @@
+use crate::{DailyProblem, Error, Example, Params, Result, Simulation};
+use lazy_static::lazy_static;
+use regex::Regex;
+use std::{collections::VecDeque, str::Lines};
+
+pub struct SupplyStacks;
+impl DailyProblem for SupplyStacks {
+    fn name(&self) -> &str {
+        "Day 5: Supply Stacks"
+    }
+    fn index(&self) -> u8 {
+        5
+    }
+    fn part_one(&self, input: &str, _params: &Params) -> Result<String> {
+        let mut state = parse_input(input, false)?;
+        while !state.step()? {}
+        Ok(state.crates_on_top())
+    }
+    fn part_two(&self, input: &str, _params: &Params) -> Result<String> {
+        let mut state = parse_input(input, true)?;
+        while !state.step()? {}
+        Ok(state.crates_on_top())
+    }
+    fn example(&self) -> Option<Example> {
+        Some(Example {
+            input: EXAMPLE,
+            part_one: "CMZ",
+            part_two: "MCD",
+            params: &[],
+        })
+    }
+    fn simulation<'a>(
+        &self,
+        input: &'a str,
+        part: u8,
+        _params: &Params,
+    ) -> Option<Result<Box<dyn Simulation + 'a>>> {
+        Some(parse_input(input, part == 2).map(|state| Box::new(state) as Box<dyn Simulation>))
+    }
+}
+
+const EXAMPLE: &str = "    [D]    
+[N] [C]    
+[Z] [M] [P]
+ 1   2   3 
+
+move 1 from 2 to 1
+move 3 from 1 to 3
+move 2 from 2 to 1
+move 1 from 1 to 2";
+
+#[derive(Debug)]
+pub struct PuzzleState<'a> {
+    crates: Vec<VecDeque<char>>,
+    instructions: InstructionIter<'a>,
+    can_move_multiple_crates: bool,
+    moves: usize,
+}
+
+impl PuzzleState<'_> {
+    pub fn step(&mut self) -> Result<bool> {
+        match self.instructions.next() {
+            Some(instruction) => {
+                let instruction = instruction?;
+                let line = instruction.line;
+                self.apply_instruction(instruction).ok_or_else(|| {
+                    Error::no_solution(&format!(
+                        "the instruction on line {} can't be carried out",
+                        line
+                    ))
+                })?;
+                self.moves += 1;
+                Ok(false)
+            }
+            None => Ok(true),
+        }
+    }
+
+    fn apply_instruction(&mut self, instruction: Instruction) -> Option<()> {
+        if instruction.from == 0
+            || instruction.to == 0
+            || instruction.to > self.crates.len()
+            || self.crates.get(instruction.from - 1)?.len() < instruction.to_move as usize
+        {
+            return None;
+        }
+        match self.can_move_multiple_crates {
+            false => {
+                for _ in 0..instruction.to_move {
+                    let crates = &mut self.crates;
+                    let c = crates
+                        .get_mut(instruction.from - 1)
+                        .unwrap()
+                        .pop_front()
+                        .unwrap();
+                    crates.get_mut(instruction.to - 1).unwrap().push_front(c);
+                }
+            }
+            true => {
+                let mut crates_in_crane = vec![];
+                for _ in 0..instruction.to_move {
+                    let c = self
+                        .crates
+                        .get_mut(instruction.from - 1)
+                        .unwrap()
+                        .pop_front()
+                        .unwrap();
+                    crates_in_crane.push(c);
+                }
+
+                for _ in 0..instruction.to_move {
+                    let c = crates_in_crane.pop().unwrap();
+                    self.crates
+                        .get_mut(instruction.to - 1)
+                        .unwrap()
+                        .push_front(c);
+                }
+            }
+        }
+        Some(())
+    }
+
+    pub fn crates_on_top(&self) -> String {
+        let mut string = "".to_string();
+        for c in &self.crates {
+            if let Some(top) = c.front() {
+                string.push(*top);
+            }
+        }
+        string
+    }
+
+    // the stacks drawn the way the puzzle input draws them
+    pub fn as_string(&self) -> String {
+        let height = self.crates.iter().map(|c| c.len()).max().unwrap_or(0);
+        let mut s = String::new();
+        for level in (0..height).rev() {
+            let row: Vec<String> = self
+                .crates
+                .iter()
+                .map(|c| match c.len() > level {
+                    true => format!("[{}]", c[c.len() - 1 - level]),
+                    false => "   ".to_string(),
+                })
+                .collect();
+            s.push_str(row.join(" ").trim_end());
+            s.push('\n');
+        }
+        let numbers: Vec<String> = (1..=self.crates.len())
+            .map(|n| format!(" {} ", n))
+            .collect();
+        s.push_str(&numbers.join(" "));
+        s.push('\n');
+        s
+    }
+}
+
+impl Simulation for PuzzleState<'_> {
+    // a step is one instruction
+    fn step(&mut self) -> Result<bool> {
+        PuzzleState::step(self)
+    }
+
+    fn render(&self) -> String {
+        self.as_string()
+    }
+
+    fn counters(&self) -> Vec<(String, String)> {
+        vec![
+            ("moves made".to_string(), self.moves.to_string()),
+            ("crates on top".to_string(), self.crates_on_top()),
+        ]
+    }
+}
+
+#[derive(Debug)]
+struct Instruction {
+    to_move: u32,
+    from: usize,
+    to: usize,
+    line: usize,
+}
+
+pub fn parse_input(input: &str, can_move_multiple_crates: bool) -> Result<PuzzleState<'_>> {
+    let mut chunks = input.split("\n\n");
+    let crates = chunks.next().unwrap();
+    // the blank line and moves should come straight after the drawing
+    let instructions = chunks
+        .next()
+        .ok_or_else(|| Error::parse(crates.lines().count() + 1, ""))?;
+    Ok(PuzzleState {
+        crates: parse_crates(crates)?,
+        instructions: InstructionIter {
+            lines: instructions.lines(),
+            line: crates.lines().count() + 1,
+        },
+        can_move_multiple_crates,
+        moves: 0,
+    })
+}
+
+fn parse_crates(input: &str) -> Result<Vec<VecDeque<char>>> {
+    let num_stacks = input.lines().last().unwrap_or("").replace(' ', "").len();
+    let mut stacks = vec![VecDeque::new(); num_stacks];
+    for (line, slice) in input.lines().enumerate() {
+        if !slice.trim().starts_with('[') {
+            break;
+        }
+
+        for stack_index in 0..num_stacks {
+            let mut potential_crate = match slice.get(stack_index * 4..stack_index * 4 + 3) {
+                Some(potential_crate) => potential_crate.chars(),
+                None => continue,
+            };
+            if potential_crate.next() == Some('[') {
+                stacks.get_mut(stack_index).unwrap().push_back(
+                    potential_crate
+                        .next()
+                        .ok_or_else(|| Error::parse(line + 1, slice))?,
+                );
+            }
+        }
+    }
+    Ok(stacks)
+}
+
+#[derive(Debug)]
+struct InstructionIter<'a> {
+    lines: Lines<'a>,
+    line: usize,
+}
+
+impl<'a> Iterator for InstructionIter<'a> {
+    type Item = Result<Instruction>;
+    fn next(&mut self) -> Option<Result<Instruction>> {
+        lazy_static! {
+            static ref RE: Regex = Regex::new("move (\\d*) from (\\d*) to (\\d*)").unwrap();
+        }
+        let text = self.lines.next()?;
+        self.line += 1;
+        let instruction = RE.captures(text).and_then(|caps| {
+            Some(Instruction {
+                to_move: caps.get(1)?.as_str().parse().ok()?,
+                from: caps.get(2)?.as_str().parse().ok()?,
+                to: caps.get(3)?.as_str().parse().ok()?,
+                line: self.line,
+            })
+        });
+        Some(instruction.ok_or_else(|| Error::parse(self.line, text)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{parse_input, EXAMPLE};
+    use crate::Error;
+
+    #[test]
+    fn drawing() {
+        let mut state = parse_input(EXAMPLE, false).unwrap();
+        assert_eq!(
+            state.as_string(),
+            "    [D]\n[N] [C]\n[Z] [M] [P]\n 1   2   3 \n"
+        );
+        state.step().unwrap();
+        assert_eq!(
+            state.as_string(),
+            "[D]\n[N] [C]\n[Z] [M] [P]\n 1   2   3 \n"
+        );
+    }
+
+    #[test]
+    fn missing_moves() {
+        assert_eq!(parse_input("", false).err(), Some(Error::parse(1, "")));
+        assert_eq!(
+            parse_input("[A]\n 1 ", false).err(),
+            Some(Error::parse(3, ""))
+        );
+    }
+}
@@ -1,4 +1,4 @@
-use aoc_2022::DailyProblem;
+use crate::{DailyProblem, Error, Example, Params, Result};
 
 pub struct TreetopTreeHouse;
 
@@ -9,16 +9,32 @@ impl DailyProblem for TreetopTreeHouse {
     fn index(&self) -> u8 {
         8
     }
-    fn solutions(&self, input: &str) -> (String, String) {
-        let mut trees = TreeGrid::new(input);
-        trees.scan();
-        (
-            trees.count_visible_trees().to_string(),
-            trees.highest_scenic_score().to_string(),
-        )
+    fn part_one(&self, input: &str, _params: &Params) -> Result<String> {
+        let mut trees = TreeGrid::new(input)?;
+        trees.scan_visibility();
+        Ok(trees.count_visible_trees().to_string())
+    }
+    fn part_two(&self, input: &str, _params: &Params) -> Result<String> {
+        let mut trees = TreeGrid::new(input)?;
+        trees.scan_scenic_score();
+        Ok(trees.highest_scenic_score().to_string())
+    }
+    fn example(&self) -> Option<Example> {
+        Some(Example {
+            input: EXAMPLE,
+            part_one: "21",
+            part_two: "8",
+            params: &[],
+        })
     }
 }
 
+const EXAMPLE: &str = "30373
+25512
+65332
+33549
+35390";
+
 struct Tree {
     height: u8,
     visible_from_top: bool,
@@ -29,15 +45,15 @@ struct Tree {
 }
 
 impl Tree {
-    fn new(input: char) -> Self {
-        Tree {
-            height: input.to_string().parse().unwrap(),
+    fn new(input: char) -> Option<Self> {
+        Some(Tree {
+            height: input.to_digit(10)? as u8,
             visible_from_top: false,
             visible_from_left: false,
             visible_from_right: false,
             visible_from_bottom: false,
             scenic_score: 0,
-        }
+        })
     }
 
     fn visible(&self) -> bool {
@@ -48,24 +64,32 @@ impl Tree {
     }
 }
 
-struct TreeGrid {
+pub struct TreeGrid {
     trees: Vec<Vec<Tree>>,
 }
 
 impl TreeGrid {
-    fn new(input: &str) -> Self {
-        let mut trees = vec![];
-        for line in input.lines() {
-            let mut tree_line = vec![];
-            for c in line.chars() {
-                tree_line.push(Tree::new(c));
-            }
+    pub fn new(input: &str) -> Result<Self> {
+        let mut trees: Vec<Vec<Tree>> = vec![];
+        for (line_index, line) in input.lines().enumerate() {
+            let tree_line = line
+                .chars()
+                .map(Tree::new)
+                .collect::<Option<Vec<Tree>>>()
+                .filter(|tree_line| match trees.first() {
+                    Some(first_line) => first_line.len() == tree_line.len(),
+                    None => !tree_line.is_empty(),
+                })
+                .ok_or_else(|| Error::parse(line_index + 1, line))?;
             trees.push(tree_line);
         }
-        TreeGrid { trees }
+        if trees.is_empty() {
+            return Err(Error::parse(1, input));
+        }
+        Ok(TreeGrid { trees })
     }
 
-    fn count_visible_trees(&self) -> u32 {
+    pub fn count_visible_trees(&self) -> u32 {
         let mut count = 0;
         for line in self.trees.iter() {
             for tree in line {
@@ -77,7 +101,7 @@ impl TreeGrid {
         count
     }
 
-    fn highest_scenic_score(&self) -> u32 {
+    pub fn highest_scenic_score(&self) -> u32 {
         self.trees
             .iter()
             .map(|l| l.iter().map(|t| t.scenic_score).max().unwrap())
@@ -93,7 +117,7 @@ impl TreeGrid {
         self.trees.get(y).unwrap().get(x).unwrap()
     }
 
-    fn scan_scenic_score(&mut self) {
+    pub fn scan_scenic_score(&mut self) {
         for y in 0..self.trees.len() {
             for x in 0..self.trees.first().unwrap().len() {
                 let tree_height = self.height_at(x, y);
@@ -151,12 +175,11 @@ impl TreeGrid {
         }
     }
 
-    fn scan(&mut self) {
+    pub fn scan_visibility(&mut self) {
         self.scan_top();
         self.scan_bottom();
         self.scan_left();
         self.scan_right();
-        self.scan_scenic_score();
     }
 
     fn scan_top(&mut self) {
@@ -247,8 +270,9 @@ mod tests {
 65332
 33549
 35390",
-        );
-        trees.scan();
+        )
+        .unwrap();
+        trees.scan_scenic_score();
         assert_eq!(trees.tree_at(0, 0).height, 3);
         assert_eq!(trees.tree_at(3, 0).height, 7);
         assert_eq!(trees.tree_at(2, 1).scenic_score, 4);
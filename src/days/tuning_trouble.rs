@@ -1,6 +1,6 @@
 use std::collections::HashSet;
 
-use aoc_2022::DailyProblem;
+use crate::{DailyProblem, Error, Example, Params, Result};
 
 pub struct TuningTrouble;
 
@@ -11,15 +11,29 @@ impl DailyProblem for TuningTrouble {
     fn index(&self) -> u8 {
         6
     }
-    fn solutions(&self, input: &str) -> (String, String) {
-        (
-            find_start_of_packet_index(input, 4).unwrap().to_string(),
-            find_start_of_packet_index(input, 14).unwrap().to_string(),
-        )
+    fn part_one(&self, input: &str, _params: &Params) -> Result<String> {
+        Ok(find_start_of_packet_index(input, 4)
+            .ok_or_else(|| Error::no_solution("the datastream has no marker"))?
+            .to_string())
+    }
+    fn part_two(&self, input: &str, _params: &Params) -> Result<String> {
+        Ok(find_start_of_packet_index(input, 14)
+            .ok_or_else(|| Error::no_solution("the datastream has no marker"))?
+            .to_string())
+    }
+    fn example(&self) -> Option<Example> {
+        Some(Example {
+            input: EXAMPLE,
+            part_one: "7",
+            part_two: "19",
+            params: &[],
+        })
     }
 }
 
-fn find_start_of_packet_index(input: &str, length: usize) -> Option<usize> {
+const EXAMPLE: &str = "mjqjpqmgbljsphdztnvjfqwrcgsmlb";
+
+pub fn find_start_of_packet_index(input: &str, length: usize) -> Option<usize> {
     let mut index = length;
     while index < input.len() {
         if is_start_of_packet(&input[(index - length)..index]) {
@@ -40,7 +54,7 @@ pub fn is_start_of_packet(input: &str) -> bool {
 
 #[cfg(test)]
 mod tests {
-    use crate::tuning_trouble::*;
+    use crate::days::tuning_trouble::*;
     #[test]
     fn is_start_of_packet_test() {
         assert!(!is_start_of_packet("mjqj"));
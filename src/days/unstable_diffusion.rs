@@ -0,0 +1,301 @@
+use std::{
+    collections::{HashMap, HashSet},
+    hash::{BuildHasherDefault, Hasher},
+};
+
+use crate::{DailyProblem, Error, Example, Param, Params, Result, Simulation};
+
+pub struct UnstableDiffusion;
+
+const PART_1_ROUNDS: Param = Param {
+    name: "part1_rounds",
+    default: 10,
+    description: "how many rounds the elves spread out for in part 1",
+};
+
+impl DailyProblem for UnstableDiffusion {
+    fn name(&self) -> &str {
+        "Day 23: Unstable Diffusion"
+    }
+    fn index(&self) -> u8 {
+        23
+    }
+    fn params(&self) -> &[Param] {
+        &[PART_1_ROUNDS]
+    }
+    fn part_one(&self, input: &str, params: &Params) -> Result<String> {
+        let mut grove = Grove::new(input)?;
+        for _ in 0..params.get(&PART_1_ROUNDS) {
+            grove.step();
+        }
+        Ok(grove.empty_tiles().to_string())
+    }
+    fn part_two(&self, input: &str, _params: &Params) -> Result<String> {
+        let mut grove = Grove::new(input)?;
+        while grove.step() {}
+        Ok(grove.rounds.to_string())
+    }
+    fn example(&self) -> Option<Example> {
+        Some(Example {
+            input: EXAMPLE,
+            part_one: "110",
+            part_two: "20",
+            params: &[],
+        })
+    }
+    fn simulation<'a>(
+        &self,
+        input: &'a str,
+        _part: u8,
+        _params: &Params,
+    ) -> Option<Result<Box<dyn Simulation + 'a>>> {
+        Some(Grove::new(input).map(|grove| Box::new(grove) as Box<dyn Simulation>))
+    }
+}
+
+const EXAMPLE: &str = "....#..
+..###.#
+#...#.#
+.#...##
+#.###..
+##.#.##
+.#..#..";
+
+// (row, col) offsets, rows going down, from the top left across and down
+const NEIGHBOURS: [(i64, i64); 8] = [
+    (-1, -1),
+    (-1, 0),
+    (-1, 1),
+    (0, -1),
+    (0, 1),
+    (1, -1),
+    (1, 0),
+    (1, 1),
+];
+
+// a way an elf might move, with the three tiles (as indexes into
+// NEIGHBOURS) that have to be empty for it to go that way
+#[derive(Debug, Clone, Copy)]
+struct Direction {
+    name: &'static str,
+    step: (i64, i64),
+    checks: [usize; 3],
+}
+
+// in the order they're considered on the first round
+const DIRECTIONS: [Direction; 4] = [
+    Direction {
+        name: "north",
+        step: (-1, 0),
+        checks: [0, 1, 2],
+    },
+    Direction {
+        name: "south",
+        step: (1, 0),
+        checks: [5, 6, 7],
+    },
+    Direction {
+        name: "west",
+        step: (0, -1),
+        checks: [0, 3, 5],
+    },
+    Direction {
+        name: "east",
+        step: (0, 1),
+        checks: [2, 4, 7],
+    },
+];
+
+// the std hasher is built to stand up to keys picked by an attacker, which
+// elves aren't, and it was most of the time spent on part 2. this mixes in
+// each coordinate with a multiply instead
+#[derive(Default)]
+struct PositionHasher(u64);
+
+impl Hasher for PositionHasher {
+    fn finish(&self) -> u64 {
+        self.0
+    }
+
+    fn write(&mut self, bytes: &[u8]) {
+        bytes.iter().for_each(|&b| self.write_u64(b as u64));
+    }
+
+    fn write_i64(&mut self, n: i64) {
+        self.write_u64(n as u64);
+    }
+
+    fn write_u64(&mut self, n: u64) {
+        self.0 = (self.0.rotate_left(5) ^ n).wrapping_mul(0x517c_c1b7_2722_0a95);
+    }
+}
+
+type Fast = BuildHasherDefault<PositionHasher>;
+
+// the elves' positions, with nothing stored for the empty ground around
+// them since they spread out as far as they like
+#[derive(Debug)]
+pub struct Grove {
+    elves: HashSet<(i64, i64), Fast>,
+    pub rounds: usize,
+    settled: bool,
+}
+
+impl Grove {
+    pub fn new(input: &str) -> Result<Self> {
+        let mut elves = HashSet::default();
+        for (row, line) in input.lines().enumerate() {
+            for (col, c) in line.trim().chars().enumerate() {
+                match c {
+                    '#' => elves.insert((row as i64, col as i64)),
+                    '.' => continue,
+                    _ => return Err(Error::parse(row + 1, line)),
+                };
+            }
+        }
+        Ok(Self {
+            elves,
+            rounds: 0,
+            settled: false,
+        })
+    }
+
+    // plays out one round, returning whether any elf moved
+    pub fn step(&mut self) -> bool {
+        // where each elf that wants to move wants to go
+        let mut proposals = vec![];
+        let mut wanted = HashMap::<_, u32, Fast>::default();
+        for &elf in &self.elves {
+            let occupied =
+                NEIGHBOURS.map(|(dr, dc)| self.elves.contains(&(elf.0 + dr, elf.1 + dc)));
+            if !occupied.contains(&true) {
+                continue;
+            }
+            let proposal = (0..4)
+                .map(|i| DIRECTIONS[(self.rounds + i) % 4])
+                .find(|d| !d.checks.iter().any(|&n| occupied[n]));
+            if let Some(Direction { step: (dr, dc), .. }) = proposal {
+                let target = (elf.0 + dr, elf.1 + dc);
+                proposals.push((elf, target));
+                *wanted.entry(target).or_insert(0) += 1;
+            }
+        }
+
+        let mut moved = false;
+        for (elf, target) in proposals {
+            if wanted[&target] == 1 {
+                self.elves.remove(&elf);
+                self.elves.insert(target);
+                moved = true;
+            }
+        }
+        self.rounds += 1;
+        self.settled = !moved;
+        moved
+    }
+
+    // (top, left, bottom, right) of the smallest rectangle around the elves
+    fn bounds(&self) -> (i64, i64, i64, i64) {
+        let rows = self.elves.iter().map(|e| e.0);
+        let cols = self.elves.iter().map(|e| e.1);
+        (
+            rows.clone().min().unwrap_or(0),
+            cols.clone().min().unwrap_or(0),
+            rows.max().unwrap_or(-1),
+            cols.max().unwrap_or(-1),
+        )
+    }
+
+    pub fn empty_tiles(&self) -> usize {
+        let (top, left, bottom, right) = self.bounds();
+        ((bottom - top + 1) * (right - left + 1)) as usize - self.elves.len()
+    }
+
+    // the smallest rectangle around the elves
+    pub fn as_string(&self) -> String {
+        let (top, left, bottom, right) = self.bounds();
+        let mut s = String::new();
+        for row in top..=bottom {
+            for col in left..=right {
+                s.push(match self.elves.contains(&(row, col)) {
+                    true => '#',
+                    false => '.',
+                });
+            }
+            s.push('\n');
+        }
+        s
+    }
+}
+
+impl Simulation for Grove {
+    // a step is a whole round, finished once nobody moves
+    fn step(&mut self) -> Result<bool> {
+        if !self.settled {
+            Grove::step(self);
+        }
+        Ok(self.settled)
+    }
+
+    fn render(&self) -> String {
+        self.as_string()
+    }
+
+    fn counters(&self) -> Vec<(String, String)> {
+        vec![
+            ("rounds".to_string(), self.rounds.to_string()),
+            ("elves".to_string(), self.elves.len().to_string()),
+            ("empty tiles".to_string(), self.empty_tiles().to_string()),
+            (
+                "looking first".to_string(),
+                DIRECTIONS[self.rounds % 4].name.to_string(),
+            ),
+        ]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Grove, EXAMPLE};
+
+    #[test]
+    fn small_example() {
+        let mut grove = Grove::new(".....\n..##.\n..#..\n.....\n..##.\n.....").unwrap();
+        assert_eq!(grove.as_string(), "##\n#.\n..\n##\n");
+        assert!(grove.step());
+        assert_eq!(grove.as_string(), "##\n..\n#.\n.#\n#.\n");
+        grove.step();
+        grove.step();
+        assert_eq!(
+            grove.as_string(),
+            "..#..\n....#\n#....\n....#\n.....\n..#..\n"
+        );
+        assert!(!grove.step());
+        assert_eq!(grove.rounds, 4);
+    }
+
+    #[test]
+    fn rounds() {
+        let mut grove = Grove::new(EXAMPLE).unwrap();
+        assert_eq!(grove.empty_tiles(), 27);
+        for _ in 0..10 {
+            grove.step();
+        }
+        assert_eq!(
+            grove.as_string(),
+            "......#.....
+..........#.
+.#.#..#.....
+.....#......
+..#.....#..#
+#......##...
+....##......
+.#........#.
+...#.#..#...
+............
+...#..#..#..
+"
+        );
+        assert!(Grove::new("..#\n.x.").is_err());
+    }
+}
@@ -0,0 +1,207 @@
+use std::fmt;
+
+pub type Result<T> = std::result::Result<T, Error>;
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Error {
+    // a line (or block of lines) of the puzzle input didn't parse,
+    // line numbers start at 1 like they do in an editor
+    Parse {
+        day: Option<u8>,
+        part: Option<u8>,
+        line: usize,
+        text: String,
+    },
+    // the input parsed, but the puzzle has no answer for it
+    NoSolution {
+        day: Option<u8>,
+        part: Option<u8>,
+        reason: String,
+    },
+    // the input couldn't be read or downloaded
+    Input {
+        day: Option<u8>,
+        reason: String,
+    },
+    // the solver was given up on, because it ran out of time or panicked
+    Aborted {
+        day: Option<u8>,
+        part: Option<u8>,
+        reason: String,
+    },
+}
+
+impl Error {
+    pub fn parse(line: usize, text: &str) -> Self {
+        Self::Parse {
+            day: None,
+            part: None,
+            line,
+            text: text.to_string(),
+        }
+    }
+
+    pub fn no_solution(reason: &str) -> Self {
+        Self::NoSolution {
+            day: None,
+            part: None,
+            reason: reason.to_string(),
+        }
+    }
+
+    pub fn input(reason: &str) -> Self {
+        Self::Input {
+            day: None,
+            reason: reason.to_string(),
+        }
+    }
+
+    pub fn timed_out() -> Self {
+        Self::Aborted {
+            day: None,
+            part: None,
+            reason: "timed out".to_string(),
+        }
+    }
+
+    pub fn panicked(message: &str) -> Self {
+        Self::Aborted {
+            day: None,
+            part: None,
+            reason: format!("panicked: {}", message),
+        }
+    }
+
+    pub fn in_day(mut self, index: u8) -> Self {
+        match &mut self {
+            Self::Parse { day, .. }
+            | Self::NoSolution { day, .. }
+            | Self::Input { day, .. }
+            | Self::Aborted { day, .. } => {
+                day.get_or_insert(index);
+            }
+        }
+        self
+    }
+
+    pub fn in_part(mut self, index: u8) -> Self {
+        match &mut self {
+            Self::Parse { part, .. }
+            | Self::NoSolution { part, .. }
+            | Self::Aborted { part, .. } => {
+                part.get_or_insert(index);
+            }
+            Self::Input { .. } => {}
+        }
+        self
+    }
+}
+
+fn write_context(f: &mut fmt::Formatter, day: &Option<u8>, part: &Option<u8>) -> fmt::Result {
+    match (day, part) {
+        (Some(day), Some(part)) => write!(f, "Day {}, part {}: ", day, part),
+        (Some(day), None) => write!(f, "Day {}: ", day),
+        (None, Some(part)) => write!(f, "Part {}: ", part),
+        (None, None) => Ok(()),
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::Parse {
+                day,
+                part,
+                line,
+                text,
+            } => {
+                write_context(f, day, part)?;
+                write!(f, "invalid input on line {}: {:?}", line, text)
+            }
+            Self::NoSolution { day, part, reason } => {
+                write_context(f, day, part)?;
+                write!(f, "no solution: {}", reason)
+            }
+            Self::Input { day, reason } => {
+                write_context(f, day, &None)?;
+                write!(f, "could not get input: {}", reason)
+            }
+            Self::Aborted { day, part, reason } => {
+                write_context(f, day, part)?;
+                write!(f, "{}", reason)
+            }
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+// parses every line of the input with f, reporting the first line it
+// couldn't make sense of
+pub fn parse_lines<T>(input: &str, f: impl Fn(&str) -> Option<T>) -> Result<Vec<T>> {
+    input
+        .lines()
+        .enumerate()
+        .map(|(index, line)| f(line).ok_or_else(|| Error::parse(index + 1, line)))
+        .collect()
+}
+
+// same as parse_lines, but for inputs made of blank-line-separated blocks,
+// a bad block is reported by the line it starts on
+pub fn parse_blocks<T>(input: &str, f: impl Fn(&str) -> Option<T>) -> Result<Vec<T>> {
+    let mut line = 1;
+    input
+        .split("\n\n")
+        .map(|block| {
+            let block_line = line;
+            line += block.lines().count() + 1;
+            f(block).ok_or_else(|| Error::parse(block_line, block))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{parse_blocks, parse_lines, Error};
+
+    #[test]
+    fn parse_lines_reports_line() {
+        assert_eq!(
+            parse_lines("1\n2\nthree\n4", |l| l.parse::<u32>().ok()),
+            Err(Error::parse(3, "three"))
+        );
+        assert_eq!(
+            parse_lines("1\n2", |l| l.parse::<u32>().ok()),
+            Ok(vec![1, 2])
+        );
+    }
+
+    #[test]
+    fn parse_blocks_reports_first_line() {
+        assert_eq!(
+            parse_blocks("1\n2\n\n3\n\nfour\n5", |b| b
+                .lines()
+                .map(|l| l.parse::<u32>().ok())
+                .sum::<Option<u32>>()),
+            Err(Error::parse(6, "four\n5"))
+        );
+    }
+
+    #[test]
+    fn display() {
+        assert_eq!(
+            Error::parse(3, "oops").in_part(2).in_day(7).to_string(),
+            "Day 7, part 2: invalid input on line 3: \"oops\""
+        );
+        assert_eq!(
+            Error::no_solution("no start of packet")
+                .in_day(6)
+                .to_string(),
+            "Day 6: no solution: no start of packet"
+        );
+        assert_eq!(
+            Error::timed_out().in_part(1).in_day(14).to_string(),
+            "Day 14, part 1: timed out"
+        );
+    }
+}
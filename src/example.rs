@@ -0,0 +1,39 @@
+use crate::{DailyProblem, Params};
+
+// a puzzle's worked example, with the answers the puzzle text gives for it
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Example {
+    pub input: &'static str,
+    pub part_one: &'static str,
+    pub part_two: &'static str,
+    // the example is often smaller than the real input, day 15 only
+    // looks at row 10 instead of 2000000
+    pub params: &'static [(&'static str, u64)],
+}
+
+impl Example {
+    pub fn params(&self) -> Params {
+        let mut params = Params::default();
+        for (name, value) in self.params {
+            params.set(name, *value);
+        }
+        params
+    }
+}
+
+// panics unless both parts of the problem's example come out right
+pub fn check_example(problem: &dyn DailyProblem) {
+    let example = problem
+        .example()
+        .unwrap_or_else(|| panic!("{} has no example", problem.name()));
+    let params = example.params();
+    for (part, expected) in [(1, example.part_one), (2, example.part_two)] {
+        assert_eq!(
+            problem.solve(example.input, part, &params).as_deref(),
+            Ok(expected),
+            "{}, part {}",
+            problem.name(),
+            part
+        );
+    }
+}
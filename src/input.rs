@@ -0,0 +1,529 @@
+use std::{
+    env, fs,
+    io::{self, Read},
+    path::{Path, PathBuf},
+    sync::Mutex,
+    thread,
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+use curl::easy::Easy;
+
+use crate::{cookie_sources, data_path, find_cookie, year_path, CookieSource, Error, Result, DAYS};
+
+// the profile used when none is given, for the account whose cookie is
+// found the usual way
+pub const DEFAULT_PROFILE: &str = "default";
+
+// where a day's puzzle input comes from
+#[derive(Debug, Clone, PartialEq)]
+pub enum InputSource {
+    // src/data/<year>/<day>/default, downloaded from the site the first
+    // time it's needed
+    Cached,
+    // the same, for someone else's input kept under another name
+    Profile(String),
+    File(PathBuf),
+    Stdin,
+    Inline(String),
+}
+
+impl InputSource {
+    // "-" is stdin, anything else is a path
+    pub fn from_arg(arg: &str) -> Self {
+        match arg {
+            "-" => Self::Stdin,
+            path => Self::File(PathBuf::from(path)),
+        }
+    }
+
+    // the saved input for a profile
+    pub fn cached(profile: &str) -> Self {
+        match profile == DEFAULT_PROFILE {
+            true => Self::Cached,
+            false => Self::Profile(profile.to_string()),
+        }
+    }
+
+    pub fn read(&self, year: u16, day: u8) -> Result<String> {
+        match self {
+            Self::Cached => read_profile(year, day, DEFAULT_PROFILE),
+            Self::Profile(profile) => read_profile(year, day, profile),
+            Self::File(path) => fs::read_to_string(path)
+                .map_err(|e| Error::input(&format!("could not read {}: {}", path.display(), e))),
+            Self::Stdin => {
+                let mut input = String::new();
+                io::stdin()
+                    .read_to_string(&mut input)
+                    .map_err(|e| Error::input(&format!("could not read stdin: {}", e)))?;
+                Ok(input)
+            }
+            Self::Inline(input) => Ok(input.clone()),
+        }
+    }
+}
+
+// a missing input is downloaded, and so is an error page saved by an older
+// version, but anything else that stops it being read is reported rather
+// than spending a request on it
+fn read_profile(year: u16, day: u8, profile: &str) -> Result<String> {
+    let path = input_path(year, day, profile)?;
+    match fs::read_to_string(&path) {
+        Ok(s) if !looks_like_error_page(&s) => Ok(s),
+        Ok(_) => fetch(year, day, profile),
+        Err(e) if e.kind() == io::ErrorKind::NotFound => fetch(year, day, profile),
+        Err(e) => Err(Error::input(&format!(
+            "could not read {}: {}",
+            path.display(),
+            e
+        ))),
+    }
+}
+
+// profile names end up in file names, so they're kept simple
+pub fn valid_profile(name: &str) -> bool {
+    !name.is_empty()
+        && name
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_')
+}
+
+// src/data/<year>/<day>/<profile>
+pub fn input_path(year: u16, day: u8, profile: &str) -> Result<PathBuf> {
+    Ok(year_path(year, &day.to_string())?.join(profile))
+}
+
+// every profile with an input saved for the day, in name order
+pub fn profiles(year: u16, day: u8) -> Result<Vec<String>> {
+    let path = input_path(year, day, DEFAULT_PROFILE)?;
+    let mut profiles: Vec<String> = match path.parent().and_then(|dir| fs::read_dir(dir).ok()) {
+        Some(entries) => entries
+            .filter_map(|entry| entry.ok()?.file_name().into_string().ok())
+            .filter(|name| valid_profile(name))
+            .collect(),
+        None => vec![],
+    };
+    profiles.sort();
+    Ok(profiles)
+}
+
+// moves inputs and answers saved by older versions into place, once at
+// startup. back when there was only 2022 they were kept straight in
+// src/data, and before there were profiles each input was the file
+// <year>/<day> where its directory now is
+pub fn migrate_data_dir() -> Result<()> {
+    // outside the project there's no data directory to tidy up
+    match data_path("") {
+        Ok(data_dir) => migrate(&data_dir),
+        Err(_) => Ok(()),
+    }
+}
+
+fn migrate(data_dir: &Path) -> Result<()> {
+    let old_names = DAYS
+        .flat_map(|day| [day.to_string(), format!("{}.fetched", day)])
+        .chain(["answers.toml".to_string()]);
+    for name in old_names {
+        let (old, new) = (data_dir.join(&name), data_dir.join("2022").join(&name));
+        if old.is_file() && !new.exists() {
+            move_file(&old, &new)?;
+        }
+    }
+
+    let years: Vec<PathBuf> = match fs::read_dir(data_dir) {
+        Ok(entries) => entries
+            .filter_map(|entry| entry.ok())
+            .filter(|entry| {
+                entry
+                    .file_name()
+                    .to_str()
+                    .is_some_and(|n| n.parse::<u16>().is_ok())
+            })
+            .map(|entry| entry.path())
+            .collect(),
+        Err(_) => vec![],
+    };
+    for year_dir in years {
+        for day in DAYS {
+            let dir = year_dir.join(day.to_string());
+            if !dir.is_file() {
+                continue;
+            }
+            let moving = dir.with_extension("moving");
+            move_file(&dir, &moving)?;
+            move_file(&moving, &dir.join(DEFAULT_PROFILE))?;
+            let fetched = dir.with_extension("fetched");
+            if fetched.is_file() {
+                move_file(&fetched, &dir.join(format!("{}.fetched", DEFAULT_PROFILE)))?;
+            }
+        }
+    }
+    Ok(())
+}
+
+fn move_file(from: &Path, to: &Path) -> Result<()> {
+    to.parent()
+        .map_or(Ok(()), fs::create_dir_all)
+        .and_then(|_| fs::rename(from, to))
+        .map_err(|e| {
+            Error::input(&format!(
+                "could not move {} to {}: {}",
+                from.display(),
+                to.display(),
+                e
+            ))
+        })
+}
+
+// where inputs are downloaded from, set AOC_BASE_URL to use a local
+// stand-in instead of the real site
+const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+
+// the site asks people not to hammer it, so downloads are spaced out
+// even across separate runs
+const MIN_FETCH_INTERVAL: Duration = Duration::from_secs(5);
+
+// held for the whole of a download, so threads fetching at the same time
+// take turns instead of all reading the same last-request time
+static FETCHING: Mutex<()> = Mutex::new(());
+
+// downloads the input and saves it in src/data/<year>/<day>, even if it's
+// already there
+pub fn fetch(year: u16, day: u8, profile: &str) -> Result<String> {
+    Fetcher::new(profile)?.fetch(year, day)
+}
+
+#[derive(Debug)]
+pub struct Fetcher {
+    pub base_url: String,
+    pub data_dir: PathBuf,
+    pub profile: String,
+    pub min_interval: Duration,
+    pub cookie_sources: Vec<CookieSource>,
+}
+
+impl Fetcher {
+    pub fn new(profile: &str) -> Result<Self> {
+        if !valid_profile(profile) {
+            return Err(Error::input(&format!(
+                "\"{}\" can't be used as a profile name, only letters, digits, - and _ can",
+                profile
+            )));
+        }
+        let data_dir = data_path("")?;
+        Ok(Self {
+            base_url: env::var("AOC_BASE_URL").unwrap_or_else(|_| DEFAULT_BASE_URL.to_string()),
+            cookie_sources: cookie_sources(data_dir.clone(), profile),
+            data_dir,
+            profile: profile.to_string(),
+            min_interval: MIN_FETCH_INTERVAL,
+        })
+    }
+
+    // only a successful response is saved, as <year>/<day>/<profile> along
+    // with <profile>.fetched next to it holding the unix time it was
+    // downloaded at
+    pub fn fetch(&self, year: u16, day: u8) -> Result<String> {
+        // a download that panicked can't have left anything to clean up
+        let _turn = FETCHING.lock().unwrap_or_else(|e| e.into_inner());
+        match self.profile == DEFAULT_PROFILE {
+            true => eprintln!("Downloading file for {} problem {}", year, day),
+            false => eprintln!(
+                "Downloading file for {} problem {} as {}",
+                year, day, self.profile
+            ),
+        }
+        let cookie_string = find_cookie(&self.cookie_sources)?;
+        fs::create_dir_all(&self.data_dir).map_err(|e| {
+            Error::input(&format!(
+                "could not create {}: {}",
+                self.data_dir.display(),
+                e
+            ))
+        })?;
+        self.wait_for_turn()?;
+
+        let (status, body) = self.get(
+            &format!(
+                "{}/{}/day/{}/input",
+                self.base_url.trim_end_matches('/'),
+                year,
+                day
+            ),
+            &cookie_string,
+        )?;
+        let input = String::from_utf8(body)
+            .map_err(|e| Error::input(&format!("downloaded input is not UTF-8: {}", e)))?;
+        if status != 200 {
+            return Err(Error::input(&format!(
+                "the server responded with {}: {}",
+                status,
+                input.lines().next().unwrap_or("").trim()
+            )));
+        }
+        if looks_like_error_page(&input) {
+            return Err(Error::input(
+                "the server sent a web page instead of an input, is your cookie still valid?",
+            ));
+        }
+
+        let dir = self.data_dir.join(year.to_string()).join(day.to_string());
+        fs::create_dir_all(&dir)
+            .map_err(|e| Error::input(&format!("could not create {}: {}", dir.display(), e)))?;
+        let path = format!("{}/{}/{}", year, day, self.profile);
+        self.write(&path, &input)?;
+        self.write(&format!("{}.fetched", path), &format!("{}\n", unix_time()))?;
+        Ok(input)
+    }
+
+    // sleeps until min_interval has passed since the last request
+    fn wait_for_turn(&self) -> Result<()> {
+        let last_request_path = self.data_dir.join("last-request");
+        let last_request = fs::read_to_string(&last_request_path)
+            .ok()
+            .and_then(|s| s.trim().parse::<u64>().ok());
+        if let Some(last_request) = last_request {
+            let wait = Duration::from_secs(last_request + self.min_interval.as_secs())
+                .saturating_sub(Duration::from_secs(unix_time()));
+            if !wait.is_zero() {
+                eprintln!("Waiting {}s before downloading", wait.as_secs());
+                thread::sleep(wait);
+            }
+        }
+        self.write("last-request", &format!("{}\n", unix_time()))
+    }
+
+    fn get(&self, url: &str, cookie: &str) -> Result<(u32, Vec<u8>)> {
+        let curl_error = |e: curl::Error| Error::input(&format!("download failed: {}", e));
+        let mut handle = Easy::new();
+        handle.cookie(cookie).map_err(curl_error)?;
+        handle
+            .useragent("github.com/bassguitarbill/aoc-2022 by bassguitarbill@gmail.com")
+            .map_err(curl_error)?;
+        handle.url(url).map_err(curl_error)?;
+
+        let mut body: Vec<u8> = vec![];
+        {
+            let mut transfer = handle.transfer();
+            transfer
+                .write_function(|data| {
+                    body.extend_from_slice(data);
+                    Ok(data.len())
+                })
+                .map_err(curl_error)?;
+
+            transfer.perform().map_err(curl_error)?;
+        }
+        Ok((handle.response_code().map_err(curl_error)?, body))
+    }
+
+    fn write(&self, name: &str, contents: &str) -> Result<()> {
+        let path = self.data_dir.join(name);
+        fs::write(&path, contents)
+            .map_err(|e| Error::input(&format!("could not save {}: {}", path.display(), e)))
+    }
+}
+
+// the login page, or anything else that's obviously not a puzzle input
+fn looks_like_error_page(input: &str) -> bool {
+    let start = input.trim_start().to_lowercase();
+    input.trim().is_empty()
+        || start.starts_with("<!doctype")
+        || start.starts_with("<html")
+        || start.starts_with("puzzle inputs differ by user")
+}
+
+fn unix_time() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or(0)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        env, fs,
+        io::{Read, Write},
+        net::TcpListener,
+        path::PathBuf,
+        process, thread,
+        time::Duration,
+    };
+
+    use super::{migrate, valid_profile, Fetcher, InputSource, DEFAULT_PROFILE};
+    use crate::CookieSource;
+
+    // a stand-in for the site that answers a single request, with a 404 if
+    // it's not for the expected path
+    fn serve(path: &str, status: &str, body: &str) -> String {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let address = listener.local_addr().unwrap();
+        let request_line = format!("GET {} ", path);
+        let status = status.to_string();
+        let body = body.to_string();
+        thread::spawn(move || {
+            let (mut stream, _) = listener.accept().unwrap();
+            let mut request = vec![];
+            let mut buffer = [0; 1024];
+            while !request.ends_with(b"\r\n\r\n") {
+                let read = stream.read(&mut buffer).unwrap();
+                request.extend_from_slice(&buffer[..read]);
+            }
+            let (status, body) = if request.starts_with(request_line.as_bytes()) {
+                (status.as_str(), body.as_str())
+            } else {
+                ("404 Not Found", "404 Not Found")
+            };
+            let response = format!(
+                "HTTP/1.1 {}\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                status,
+                body.len(),
+                body
+            );
+            stream.write_all(response.as_bytes()).unwrap();
+        });
+        format!("http://{}", address)
+    }
+
+    fn test_fetcher(name: &str, base_url: String) -> Fetcher {
+        let data_dir = env::temp_dir().join(format!("aoc-2022-{}-{}", name, process::id()));
+        let _ = fs::remove_dir_all(&data_dir);
+        fs::create_dir_all(&data_dir).unwrap();
+        fs::write(data_dir.join("cookie"), "session=abc123").unwrap();
+        Fetcher {
+            base_url,
+            cookie_sources: vec![CookieSource::File(data_dir.join("cookie"))],
+            data_dir,
+            profile: DEFAULT_PROFILE.to_string(),
+            min_interval: Duration::ZERO,
+        }
+    }
+
+    #[test]
+    fn from_arg() {
+        assert_eq!(InputSource::from_arg("-"), InputSource::Stdin);
+        assert_eq!(
+            InputSource::from_arg("examples/14.txt"),
+            InputSource::File(PathBuf::from("examples/14.txt"))
+        );
+    }
+
+    #[test]
+    fn read() {
+        assert_eq!(
+            InputSource::Inline("A Y".to_string()).read(2022, 2),
+            Ok("A Y".to_string())
+        );
+        assert!(InputSource::File(PathBuf::from("does/not/exist"))
+            .read(2022, 2)
+            .is_err());
+    }
+
+    #[test]
+    fn fetch_saves_input() {
+        let fetcher = test_fetcher(
+            "fetch-saves",
+            serve("/2021/day/1/input", "200 OK", "1\n2\n"),
+        );
+        assert_eq!(fetcher.fetch(2021, 1), Ok("1\n2\n".to_string()));
+        assert_eq!(
+            fs::read_to_string(fetcher.data_dir.join("2021/1/default")).unwrap(),
+            "1\n2\n"
+        );
+        assert!(fetcher.data_dir.join("2021/1/default.fetched").exists());
+        assert!(!fetcher.data_dir.join("1").exists());
+        fs::remove_dir_all(&fetcher.data_dir).unwrap();
+    }
+
+    #[test]
+    fn fetch_profile() {
+        let mut fetcher = test_fetcher(
+            "fetch-profile",
+            serve("/2022/day/3/input", "200 OK", "abc\n"),
+        );
+        fetcher.profile = "alice".to_string();
+        fs::create_dir_all(fetcher.data_dir.join("2022/3")).unwrap();
+        fs::write(fetcher.data_dir.join("2022/3/default"), "xyz\n").unwrap();
+
+        assert_eq!(fetcher.fetch(2022, 3), Ok("abc\n".to_string()));
+        let read = |name: &str| fs::read_to_string(fetcher.data_dir.join(name)).unwrap();
+        assert_eq!(read("2022/3/alice"), "abc\n");
+        assert_eq!(read("2022/3/default"), "xyz\n");
+        assert!(fetcher.data_dir.join("2022/3/alice.fetched").exists());
+        fs::remove_dir_all(&fetcher.data_dir).unwrap();
+    }
+
+    #[test]
+    fn migrate_old_layouts() {
+        let data_dir = env::temp_dir().join(format!("aoc-2022-migrate-{}", process::id()));
+        let _ = fs::remove_dir_all(&data_dir);
+        let write = |name: &str, contents: &str| {
+            let path = data_dir.join(name);
+            fs::create_dir_all(path.parent().unwrap()).unwrap();
+            fs::write(path, contents).unwrap();
+        };
+        // from before there were years
+        write("1", "one\n");
+        write("1.fetched", "0\n");
+        write("answers.toml", "[1]\n");
+        write("cookie", "session=abc123");
+        // and from before there were profiles
+        write("2021/7", "seven\n");
+        write("2021/answers.toml", "[7]\n");
+
+        migrate(&data_dir).unwrap();
+        // twice makes no difference
+        migrate(&data_dir).unwrap();
+        let read = |name: &str| fs::read_to_string(data_dir.join(name)).unwrap();
+        assert_eq!(read("2022/1/default"), "one\n");
+        assert_eq!(read("2022/1/default.fetched"), "0\n");
+        assert_eq!(read("2022/answers.toml"), "[1]\n");
+        assert_eq!(read("2021/7/default"), "seven\n");
+        assert_eq!(read("2021/answers.toml"), "[7]\n");
+        assert_eq!(read("cookie"), "session=abc123");
+        assert!(!data_dir.join("1").exists());
+        fs::remove_dir_all(&data_dir).unwrap();
+    }
+
+    #[test]
+    fn profile_names() {
+        assert!(valid_profile("default"));
+        assert!(valid_profile("work_account-2"));
+        assert!(!valid_profile(""));
+        assert!(!valid_profile("alice.fetched"));
+        assert!(!valid_profile("../cookie"));
+        assert_eq!(InputSource::cached("default"), InputSource::Cached);
+        assert_eq!(
+            InputSource::cached("alice"),
+            InputSource::Profile("alice".to_string())
+        );
+    }
+
+    #[test]
+    fn fetch_refuses_error_pages() {
+        let fetcher = test_fetcher(
+            "fetch-404",
+            serve(
+                "/2022/day/25/input",
+                "404 Not Found",
+                "Please don't repeatedly request this endpoint",
+            ),
+        );
+        assert!(fetcher.fetch(2022, 25).is_err());
+        assert!(!fetcher.data_dir.join("2022/25").exists());
+        fs::remove_dir_all(&fetcher.data_dir).unwrap();
+
+        let fetcher = test_fetcher(
+            "fetch-login",
+            serve(
+                "/2022/day/1/input",
+                "200 OK",
+                "<!DOCTYPE html>\n<p>Please log in</p>",
+            ),
+        );
+        assert!(fetcher.fetch(2022, 1).is_err());
+        assert!(!fetcher.data_dir.join("2022/1").exists());
+        fs::remove_dir_all(&fetcher.data_dir).unwrap();
+    }
+}
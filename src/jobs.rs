@@ -0,0 +1,144 @@
+use std::{
+    any::Any,
+    collections::BTreeMap,
+    panic::{self, AssertUnwindSafe},
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        mpsc,
+    },
+    thread,
+    time::Duration,
+};
+
+use crate::{Error, Result};
+
+// runs `work` on each item using up to `jobs` threads, and hands the results
+// to `done` on the calling thread in the same order as the items, each one as
+// soon as it and everything before it has finished
+pub fn run_jobs<T, R, W, D>(jobs: usize, items: &[T], work: W, mut done: D)
+where
+    T: Sync,
+    R: Send,
+    W: Fn(&T) -> R + Sync,
+    D: FnMut(R),
+{
+    if jobs <= 1 || items.len() <= 1 {
+        items.iter().for_each(|item| done(work(item)));
+        return;
+    }
+    let next = AtomicUsize::new(0);
+    let (sender, receiver) = mpsc::channel();
+    thread::scope(|scope| {
+        for _ in 0..jobs.min(items.len()) {
+            let sender = sender.clone();
+            let (next, work) = (&next, &work);
+            scope.spawn(move || loop {
+                let i = next.fetch_add(1, Ordering::Relaxed);
+                let Some(item) = items.get(i) else {
+                    break;
+                };
+                if sender.send((i, work(item))).is_err() {
+                    break;
+                }
+            });
+        }
+        drop(sender);
+
+        // results that finished before an earlier one wait here
+        let mut waiting = BTreeMap::new();
+        let mut next_done = 0;
+        for (i, result) in receiver {
+            waiting.insert(i, result);
+            while let Some(result) = waiting.remove(&next_done) {
+                done(result);
+                next_done += 1;
+            }
+        }
+    });
+}
+
+// runs `work` on a thread of its own and waits at most `limit` for it.
+// there's no safe way to stop a thread from outside, so work that runs out
+// of time is left behind to finish (or not) by itself, and goes away when
+// the program exits. a panic comes back as an error rather than taking the
+// program down with it
+pub fn within<T, W>(limit: Option<Duration>, work: W) -> Result<T>
+where
+    T: Send + 'static,
+    W: FnOnce() -> Result<T> + Send + 'static,
+{
+    let Some(limit) = limit else {
+        return work();
+    };
+    let (sender, receiver) = mpsc::channel();
+    thread::spawn(move || {
+        let result = panic::catch_unwind(AssertUnwindSafe(work));
+        let _ = sender.send(result);
+    });
+    match receiver.recv_timeout(limit) {
+        Ok(Ok(result)) => result,
+        Ok(Err(payload)) => Err(Error::panicked(&panic_message(payload))),
+        Err(_) => Err(Error::timed_out()),
+    }
+}
+
+fn panic_message(payload: Box<dyn Any + Send>) -> String {
+    match payload.downcast::<String>() {
+        Ok(message) => *message,
+        Err(payload) => match payload.downcast::<&str>() {
+            Ok(message) => message.to_string(),
+            Err(_) => "no message".to_string(),
+        },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{thread, time::Duration};
+
+    use super::{run_jobs, within};
+    use crate::Error;
+
+    #[test]
+    fn results_in_order() {
+        let items: Vec<u64> = (0..20).collect();
+        for jobs in [1, 4, 50] {
+            let mut results = vec![];
+            run_jobs(
+                jobs,
+                &items,
+                |&n| {
+                    // the early items finish last
+                    thread::sleep(Duration::from_millis(20 - n));
+                    n * n
+                },
+                |result| results.push(result),
+            );
+            assert_eq!(results, items.iter().map(|n| n * n).collect::<Vec<_>>());
+        }
+    }
+
+    #[test]
+    fn time_limit() {
+        // generous enough for a busy machine, except where it's meant to run out
+        let limit = Some(Duration::from_secs(10));
+        assert_eq!(within(limit, || Ok(1)), Ok(1));
+        assert_eq!(within(None, || Ok(1)), Ok(1));
+        assert_eq!(
+            within(limit, || -> crate::Result<()> {
+                Err(Error::no_solution("none"))
+            }),
+            Err(Error::no_solution("none"))
+        );
+        assert_eq!(
+            within(Some(Duration::from_millis(50)), || loop {
+                thread::sleep(Duration::from_millis(10));
+            }),
+            Err::<(), _>(Error::timed_out())
+        );
+        assert_eq!(
+            within(limit, || -> crate::Result<()> { panic!("oh no") }),
+            Err(Error::panicked("oh no"))
+        );
+    }
+}
@@ -1,69 +1,178 @@
-use std::fs;
+use std::{
+    path::PathBuf,
+    sync::Arc,
+    time::{Duration, Instant},
+};
 
-use curl::easy::Easy;
+pub mod days;
 
-pub trait DailyProblem {
+mod answers;
+mod bench;
+mod cookie;
+mod error;
+mod example;
+mod input;
+mod jobs;
+mod output;
+mod params;
+mod registry;
+mod repl;
+
+pub use answers::{escape, Answers, Check};
+pub use bench::{bench, format_duration, time, Stats};
+pub use cookie::{cookie_sources, find_cookie, CookieSource};
+pub use error::{parse_blocks, parse_lines, Error, Result};
+pub use example::{check_example, Example};
+pub use input::{
+    fetch, input_path, migrate_data_dir, profiles, valid_profile, Fetcher, InputSource,
+    DEFAULT_PROFILE,
+};
+pub use jobs::{run_jobs, within};
+pub use output::{Csv, Format, Formatter, Json, Record, Text};
+pub use params::{Param, Params};
+pub use registry::{Registry, DAYS};
+pub use repl::{repl, Simulation};
+
+// Send + Sync so days can be run on several threads at once
+pub trait DailyProblem: Send + Sync {
     fn name(&self) -> &str;
     fn index(&self) -> u8;
-    fn solutions(&self, input: &str) -> (String, String);
+
+    // which event the puzzle is from, every day so far is from 2022
+    fn year(&self) -> u16 {
+        2022
+    }
+    fn part_one(&self, input: &str, params: &Params) -> Result<String>;
+    fn part_two(&self, input: &str, params: &Params) -> Result<String>;
+
+    // the numbers the puzzle text gives that can be changed with --param
+    fn params(&self) -> &[Param] {
+        &[]
+    }
+
+    // the example from the puzzle text, which cargo test checks for every day
+    fn example(&self) -> Option<Example> {
+        None
+    }
+
+    // the state the puzzle is worked out on, for days that step through
+    // one, so it can be watched with `repl`
+    fn simulation<'a>(
+        &self,
+        _input: &'a str,
+        _part: u8,
+        _params: &Params,
+    ) -> Option<Result<Box<dyn Simulation + 'a>>> {
+        None
+    }
+
+    fn solutions(&self, input: &str, params: &Params) -> Result<(String, String)> {
+        Ok((
+            self.part_one(input, params).map_err(|e| e.in_part(1))?,
+            self.part_two(input, params).map_err(|e| e.in_part(2))?,
+        ))
+    }
 }
 
+// these hand parts to threads of their own when there's a time limit, so
+// they need a day that can be shared with them
 impl dyn DailyProblem {
-    pub fn print_solutions(&self) {
-	let _input = self.get_input();
-	println!("{}", self.name());
-	println!("{}", "=".repeat(self.name().len()));
-	let (first_solution, second_solution) = self.solutions(&self.get_input());
-	println!("Part 1: {}", first_solution);
-	println!("Part 2: {}", second_solution);
+    // prints both parts, or only the one asked for, each as soon as it's solved
+    pub fn print_solutions(
+	self: &Arc<Self>,
+	source: &InputSource,
+	part: Option<u8>,
+	params: &Params,
+	limit: Option<Duration>,
+	formatter: &mut dyn Formatter,
+    ) -> Result<()> {
+	let result = self.run_parts(source, part, params, limit, &mut |record| {
+	    print!("{}", formatter.record(&record))
+	});
+	print!("{}", formatter.end_day());
+	result
     }
 
-    fn get_input(&self) -> String {
-	let mut input_file_path = project_root::get_project_root().unwrap();
-	input_file_path.push("src");
-	input_file_path.push("data");
-	input_file_path.push(self.index().to_string());
-	let input_file_path = input_file_path.to_str().unwrap();
-
-	match fs::read_to_string(input_file_path) {
-	    Ok(s) => s,
-	    Err(_) => self.download_and_return_input(input_file_path).unwrap(),
+    // solves both parts, or only the one asked for, timing each and handing
+    // it to `solved` as soon as it's done; stops at the first part that fails.
+    // with a limit, the day gets that long for all its parts together, not
+    // counting reading the input
+    pub fn run_parts(
+	self: &Arc<Self>,
+	source: &InputSource,
+	part: Option<u8>,
+	params: &Params,
+	limit: Option<Duration>,
+	solved: &mut dyn FnMut(Record),
+    ) -> Result<()> {
+	let input: Arc<str> = self.get_input(source)?.into();
+	let deadline = limit.map(|limit| Instant::now() + limit);
+	for p in [1, 2] {
+	    if part.is_some_and(|part| part != p) {
+		continue;
+	    }
+	    let (solution, elapsed) = time(|| self.solve_by(&input, p, params, deadline));
+	    solved(Record {
+		day: self.index(),
+		name: self.name().to_string(),
+		part: p,
+		answer: solution?,
+		time: elapsed,
+	    });
 	}
+	Ok(())
     }
 
-    fn download_and_return_input(&self, download_path: &str) -> Result<String, ()> {
-	println!("Downloading file for problem {}", self.index());
-
-	let mut cookie_file_path = project_root::get_project_root().unwrap();
-	cookie_file_path.push("src");
-	cookie_file_path.push("data");
-	cookie_file_path.push("cookie");
+    // runs one part, giving up on it at the deadline if there is one
+    pub fn solve_by(
+	self: &Arc<Self>,
+	input: &Arc<str>,
+	part: u8,
+	params: &Params,
+	deadline: Option<Instant>,
+    ) -> Result<String> {
+	let (problem, input, params) = (self.clone(), input.clone(), params.clone());
+	let remaining = deadline.map(|deadline| deadline.saturating_duration_since(Instant::now()));
+	within(remaining, move || problem.solve(&input, part, &params))
+	    .map_err(|e| e.in_part(part).in_day(self.index()))
+    }
+}
 
-	let cookie_string = match fs::read_to_string(cookie_file_path.to_str().unwrap()) {
-	    Ok(cs) => cs,
-	    Err(e) => panic!("Set your cookie file at \"src/data/cookie\": {}", e)
+impl dyn DailyProblem + '_ {
+    // runs just one part, 1 or 2
+    pub fn solve(&self, input: &str, part: u8, params: &Params) -> Result<String> {
+	let solution = match part {
+	    1 => self.part_one(input, params),
+	    _ => self.part_two(input, params),
 	};
+	solution.map_err(|e| e.in_part(part).in_day(self.index()))
+    }
 
-	let mut handle = Easy::new();
-	handle.cookie(&cookie_string).unwrap();
-	handle.useragent("github.com/bassguitarbill/aoc-2022 by bassguitarbill@gmail.com").unwrap();
-	handle.url(&format!("https://adventofcode.com/2022/day/{}/input", self.index())).unwrap();
-
-	let mut input: String = String::new();
-	{
-	    let mut transfer = handle.transfer();
-	    transfer.write_function(|data| {
-		input.push_str(std::str::from_utf8(data).unwrap());
-		Ok(data.len())
-	    }).unwrap();
+    pub fn get_input(&self, source: &InputSource) -> Result<String> {
+	source.read(self.year(), self.index()).map_err(|e| e.in_day(self.index()))
+    }
 
-	    transfer.perform().unwrap();
-	}
-	fs::write(download_path, &input).unwrap();
-	Ok(input)
+    // downloads the input and saves it in src/data/<year>/<day>, even if it's already there
+    pub fn fetch_input(&self, profile: &str) -> Result<String> {
+	fetch(self.year(), self.index(), profile).map_err(|e| e.in_day(self.index()))
     }
 }
 
-pub fn sum_lines(input: &str, f: fn(&str) -> u32) -> u32 {
-    input.lines().map(f).sum()
+// path to a file in src/data
+pub fn data_path(name: &str) -> Result<PathBuf> {
+    let mut path = project_root::get_project_root()
+	.map_err(|e| Error::input(&format!("could not find the project root: {}", e)))?;
+    path.push("src");
+    path.push("data");
+    path.push(name);
+    Ok(path)
+}
+
+// path to a file in src/data/<year>
+pub fn year_path(year: u16, name: &str) -> Result<PathBuf> {
+	Ok(data_path(&year.to_string())?.join(name))
+}
+
+pub fn sum_lines(input: &str, f: fn(&str) -> Option<u32>) -> Result<u32> {
+    Ok(parse_lines(input, f)?.iter().sum())
 }
@@ -1,89 +1,430 @@
-mod beacon_exclusion_zone;
-mod boiling_boulders;
-mod calorie_counting;
-mod camp_cleanup;
-mod cathode_ray_tube;
-mod distress_signal;
-mod hill_climbing_algorithm;
-mod monkey_in_the_middle;
-mod no_space_left_on_device;
-mod proboscidea_volcanium;
-mod pyroclastic_flow;
-mod regolith_reservoir;
-mod rock_paper_scissors;
-mod rope_bridge;
-mod rucksack_reorganization;
-mod supply_stacks;
-mod treetop_tree_house;
-mod tuning_trouble;
-
-use crate::beacon_exclusion_zone::BeaconExclusionZone;
-use crate::boiling_boulders::BoilingBoulders;
-use crate::calorie_counting::CalorieCounting;
-use crate::camp_cleanup::CampCleanup;
-use crate::cathode_ray_tube::CathodeRayTube;
-use crate::distress_signal::DistressSignal;
-use crate::hill_climbing_algorithm::HillClimbingAlgorithm;
-use crate::monkey_in_the_middle::MonkeyInTheMiddle;
-use crate::no_space_left_on_device::NoSpaceLeftOnDevice;
-use crate::proboscidea_volcanium::ProboscideaVolcanium;
-use crate::pyroclastic_flow::PyroclasticFlow;
-use crate::regolith_reservoir::RegolithReservoir;
-use crate::rock_paper_scissors::RockPaperScissors;
-use crate::rope_bridge::RopeBridge;
-use crate::rucksack_reorganization::RucksackReorganization;
-use crate::supply_stacks::SupplyStacks;
-use crate::treetop_tree_house::TreetopTreeHouse;
-use crate::tuning_trouble::TuningTrouble;
-use aoc_2022::DailyProblem;
-use std::env;
+mod cli;
+
+use aoc_2022::{
+    bench, days::registry, escape, format_duration, migrate_data_dir, profiles, repl, run_jobs,
+    year_path, Answers, Check, DailyProblem, Error, InputSource, Params, Registry, Result,
+    DEFAULT_PROFILE,
+};
+use cli::Command;
+use std::{
+    env, io, process,
+    time::{Duration, Instant},
+};
+use std::{path::PathBuf, sync::Arc};
 
 fn main() {
-    let problems: Vec<Box<dyn DailyProblem>> = vec![
-        Box::new(CalorieCounting {}),
-        Box::new(RockPaperScissors {}),
-        Box::new(RucksackReorganization {}),
-        Box::new(CampCleanup {}),
-        Box::new(SupplyStacks {}),
-        Box::new(TuningTrouble {}),
-        Box::new(NoSpaceLeftOnDevice {}),
-        Box::new(TreetopTreeHouse {}),
-        Box::new(RopeBridge {}),
-        Box::new(CathodeRayTube {}),
-        Box::new(MonkeyInTheMiddle {}),
-        Box::new(HillClimbingAlgorithm {}),
-        Box::new(DistressSignal {}),
-        Box::new(RegolithReservoir {}),
-        Box::new(BeaconExclusionZone {}),
-        Box::new(ProboscideaVolcanium {}),
-        Box::new(PyroclasticFlow {}),
-        Box::new(BoilingBoulders {}),
-    ];
-
-    let args: Vec<String> = env::args().collect();
-    let args = &args[1..];
-
-    if let Some(last_arg) = args.last() {
-        match last_arg.parse::<usize>() {
-            Ok(to_run) => {
-                let to_run = to_run;
-                if to_run >= 1 && to_run <= problems.len() {
-                    problems.get(to_run - 1).unwrap().print_solutions();
-                } else {
-                    panic!("Invalid problem number {}", to_run);
+    let registry = registry();
+
+    // inputs and answers saved by older versions are moved into place
+    // before anything goes looking for them
+    if !report(migrate_data_dir()) {
+        process::exit(1);
+    }
+
+    let mut args: Vec<String> = env::args().skip(1).collect();
+    let usage_error = |e: String, year: u16| -> ! {
+        eprintln!("error: {}\n", e);
+        eprint!("{}", cli::usage(&registry, year));
+        process::exit(2);
+    };
+    let latest = *registry.years().last().expect("no days are registered");
+    let year =
+        cli::take_year(&mut args, &registry.years()).unwrap_or_else(|e| usage_error(e, latest));
+    let profile = cli::take_profile(&mut args).unwrap_or_else(|e| usage_error(e, year));
+    let command = cli::parse(&args, &registry.days(year)).unwrap_or_else(|e| usage_error(e, year));
+    if let (Some(_), Command::Run { input, .. } | Command::Repl { input, .. }) =
+        (&profile, &command)
+    {
+        if *input != InputSource::Cached {
+            usage_error("--profile can't be used with --input".to_string(), year);
+        }
+    }
+    let profile = profile.unwrap_or_else(|| DEFAULT_PROFILE.to_string());
+    // the parser only lets through days that are registered
+    let problem = |day: u8| registry.get(year, day).unwrap();
+    let shared = |day: u8| registry.shared(year, day).unwrap();
+
+    let succeeded = match command {
+        Command::Run {
+            days,
+            part,
+            input,
+            params,
+            format,
+            jobs,
+            timeout,
+        } => {
+            let problems: Vec<&dyn DailyProblem> = days.iter().map(|&day| problem(day)).collect();
+            check_params(&params, &problems);
+            let input = match input {
+                InputSource::Cached => InputSource::cached(&profile),
+                input => input,
+            };
+            let mut formatter = format.formatter();
+            let mut succeeded = true;
+            if jobs == 1 {
+                for day in days {
+                    succeeded &= report(shared(day).print_solutions(
+                        &input,
+                        part,
+                        &params,
+                        timeout,
+                        formatter.as_mut(),
+                    ));
+                }
+            } else {
+                // inputs are read here first, a day at a time, so any that
+                // need downloading are fetched in order before the threads start
+                let inputs: Vec<(u8, Result<InputSource>)> = days
+                    .iter()
+                    .map(|&day| {
+                        let input = problem(day).get_input(&input);
+                        (day, input.map(InputSource::Inline))
+                    })
+                    .collect();
+                // each day's parts are held back until the days before it are printed
+                run_jobs(
+                    jobs,
+                    &inputs,
+                    |(day, input)| {
+                        let mut records = vec![];
+                        let result = match input {
+                            Ok(input) => shared(*day).run_parts(
+                                input,
+                                part,
+                                &params,
+                                timeout,
+                                &mut |record| records.push(record),
+                            ),
+                            Err(e) => Err(e.clone()),
+                        };
+                        (records, result)
+                    },
+                    |(records, result)| {
+                        for record in records {
+                            print!("{}", formatter.record(&record));
+                        }
+                        print!("{}", formatter.end_day());
+                        succeeded &= report(result);
+                    },
+                );
+            }
+            print!("{}", formatter.finish());
+            succeeded
+        }
+        Command::Fetch(day) => report(problem(day).fetch_input(&profile).map(|_| ())),
+        Command::Check(day) => match check(problem(day), &profile) {
+            Ok(unchanged) => unchanged,
+            Err(e) => report(Err(e)),
+        },
+        Command::Compare(day) => match compare(problem(day)) {
+            Ok(succeeded) => succeeded,
+            Err(e) => report(Err(e)),
+        },
+        Command::Record(days) => {
+            let problems: Vec<&dyn DailyProblem> = days.into_iter().map(problem).collect();
+            report(record(&problems, &profile))
+        }
+        Command::Verify { timeout } => match verify(&registry, year, &profile, timeout) {
+            Ok(unchanged) => unchanged,
+            Err(e) => report(Err(e)),
+        },
+        Command::Bench {
+            days,
+            part,
+            iterations,
+        } => {
+            let problems: Vec<&dyn DailyProblem> = days.into_iter().map(problem).collect();
+            run_benchmarks(&problems, &profile, part, iterations)
+        }
+        Command::Repl {
+            day,
+            part,
+            input,
+            params,
+        } => {
+            let input = match input {
+                InputSource::Cached => InputSource::cached(&profile),
+                input => input,
+            };
+            let problem = problem(day);
+            check_params(&params, &[problem]);
+            report(problem.get_input(&input).and_then(|input| {
+                repl(
+                    problem,
+                    &input,
+                    part,
+                    &params,
+                    io::stdin().lock(),
+                    io::stdout(),
+                )
+            }))
+        }
+        Command::List => {
+            print!("{}", cli::days(&registry, year));
+            true
+        }
+        Command::Help => {
+            print!("{}", cli::usage(&registry, year));
+            true
+        }
+    };
+
+    if !succeeded {
+        process::exit(1);
+    }
+}
+
+// a misspelled name would otherwise be silently ignored, so any --param
+// that none of the problems has is a usage error
+fn check_params(params: &Params, problems: &[&dyn DailyProblem]) {
+    for name in params.names() {
+        if !problems
+            .iter()
+            .any(|problem| problem.params().iter().any(|p| p.name == name))
+        {
+            match problems {
+                [problem] => eprintln!(
+                    "error: {} has no parameter called \"{}\"",
+                    problem.name(),
+                    name
+                ),
+                _ => eprintln!(
+                    "error: none of those days has a parameter called \"{}\"",
+                    name
+                ),
+            }
+            process::exit(2);
+        }
+    }
+}
+
+// prints each part next to its recorded answer, returning false if any changed
+fn check(problem: &dyn DailyProblem, profile: &str) -> Result<bool> {
+    let answers = Answers::load(&answers_path(problem.year(), profile)?)?;
+    let (first_solution, second_solution) = solve(problem, profile)?;
+    println!("{}", problem.name());
+    println!("{}", "=".repeat(problem.name().len()));
+    let mut unchanged = true;
+    for (part, answer) in [(1, first_solution), (2, second_solution)] {
+        match answers.check(problem.index(), part, &answer) {
+            Check::Pass => println!("Part {}: {} (ok)", part, answer),
+            Check::Fail(expected) => {
+                unchanged = false;
+                println!("Part {}: {} (expected {})", part, answer, expected);
+            }
+            Check::Missing => println!("Part {}: {} (no recorded answer)", part, answer),
+        }
+    }
+    Ok(unchanged)
+}
+
+// runs each problem and saves its answers, keeping the ones for other days;
+// the problems all come from the same year
+fn record(problems: &[&dyn DailyProblem], profile: &str) -> Result<()> {
+    let Some(year) = problems.first().map(|p| p.year()) else {
+        return Ok(());
+    };
+    let path = answers_path(year, profile)?;
+    let mut answers = Answers::load(&path)?;
+    for problem in problems {
+        let (first_solution, second_solution) = solve(*problem, profile)?;
+        answers.set(problem.index(), 1, &first_solution);
+        answers.set(problem.index(), 2, &second_solution);
+        println!("Recorded {}", problem.name());
+    }
+    answers.save(&path)
+}
+
+// checks every day against its recorded answers and prints a table, returning
+// false if anything changed or failed to run; missing answers are only reported
+fn verify(
+    registry: &Registry,
+    year: u16,
+    profile: &str,
+    timeout: Option<Duration>,
+) -> Result<bool> {
+    let answers = Answers::load(&answers_path(year, profile)?)?;
+    let (mut passed, mut failed, mut missing) = (0, 0, 0);
+    println!("Day  Part  Result   Answer");
+    for day in registry.days(year) {
+        let problem = registry.shared(year, day).unwrap();
+        let input: Arc<str> = match problem.get_input(&InputSource::cached(profile)) {
+            Ok(input) => input.into(),
+            Err(e) => {
+                failed += 2;
+                println!("{:>3}  {:>4}  {:<7}  {}", problem.index(), "-", "error", e);
+                continue;
+            }
+        };
+        // the same as run, the day gets the time limit for both parts together
+        let deadline = timeout.map(|timeout| Instant::now() + timeout);
+        for part in [1, 2] {
+            let answer = match problem.solve_by(&input, part, &Params::default(), deadline) {
+                Ok(answer) => answer,
+                Err(e) => {
+                    failed += 1;
+                    println!("{:>3}  {:>4}  {:<7}  {}", problem.index(), part, "error", e);
+                    continue;
+                }
+            };
+            let (result, note) = match answers.check(problem.index(), part, &answer) {
+                Check::Pass => {
+                    passed += 1;
+                    ("pass", String::new())
+                }
+                Check::Fail(expected) => {
+                    failed += 1;
+                    ("FAIL", format!(" (expected {})", one_line(&expected)))
                 }
+                Check::Missing => {
+                    missing += 1;
+                    ("missing", String::new())
+                }
+            };
+            println!(
+                "{:>3}  {:>4}  {:<7}  {}{}",
+                problem.index(),
+                part,
+                result,
+                one_line(&answer),
+                note
+            );
+        }
+    }
+    println!();
+    println!("{} passed, {} failed, {} missing", passed, failed, missing);
+    Ok(failed == 0)
+}
+
+// times each part separately, reading the input once beforehand so only
+// the solving is measured; a part that fails is reported and skipped
+fn run_benchmarks(
+    problems: &[&dyn DailyProblem],
+    profile: &str,
+    part: Option<u8>,
+    iterations: usize,
+) -> bool {
+    let mut succeeded = true;
+    let mut total = Duration::ZERO;
+    let name_width = problems.iter().map(|p| p.name().len()).max().unwrap_or(0);
+    println!(
+        "{:<name_width$}  {:>4}  {:>9}  {:>9}  {:>9}",
+        "Day", "Part", "Min", "Median", "Max"
+    );
+    for problem in problems {
+        let input = match problem.get_input(&InputSource::cached(profile)) {
+            Ok(input) => input,
+            Err(e) => {
+                succeeded = report(Err(e)) && succeeded;
+                continue;
+            }
+        };
+        for p in [1, 2] {
+            if part.is_some_and(|part| part != p) {
+                continue;
             }
-            Err(_) => {
-                for p in problems.iter() {
-                    p.print_solutions();
-                    println!();
+            match bench(iterations, || problem.solve(&input, p, &Params::default())) {
+                Ok(stats) => {
+                    total += stats.median;
+                    println!(
+                        "{:<name_width$}  {:>4}  {:>9}  {:>9}  {:>9}",
+                        problem.name(),
+                        p,
+                        format_duration(stats.min),
+                        format_duration(stats.median),
+                        format_duration(stats.max)
+                    );
                 }
+                Err(e) => succeeded = report(Err(e)) && succeeded,
             }
         }
-    } else {
-        for p in problems.iter() {
-            p.print_solutions();
-            println!();
+    }
+    println!(
+        "{:<name_width$}  {:>4}  {:>9}  {:>9}",
+        "Total",
+        "",
+        "",
+        format_duration(total)
+    );
+    println!("({} iterations each)", iterations);
+    succeeded
+}
+
+// runs one day against every profile's saved input and prints a table of
+// the answers, so different accounts' inputs can be compared
+fn compare(problem: &dyn DailyProblem) -> Result<bool> {
+    let profiles = profiles(problem.year(), problem.index())?;
+    if profiles.is_empty() {
+        return Err(Error::input("no inputs are saved for this day yet").in_day(problem.index()));
+    }
+    let mut succeeded = true;
+    let mut rows = vec![["Profile", "Part 1", "Part 2"].map(String::from).to_vec()];
+    for profile in &profiles {
+        let mut row = vec![profile.clone()];
+        match problem.get_input(&InputSource::cached(profile)) {
+            Ok(input) => {
+                for part in [1, 2] {
+                    match problem.solve(&input, part, &Params::default()) {
+                        Ok(answer) => row.push(one_line(&answer)),
+                        Err(e) => {
+                            succeeded = report(Err(e)) && succeeded;
+                            row.push("error".to_string());
+                        }
+                    }
+                }
+            }
+            Err(e) => {
+                succeeded = report(Err(e)) && succeeded;
+                row.extend(["error".to_string(), "error".to_string()]);
+            }
+        }
+        rows.push(row);
+    }
+
+    let width = |i: usize| {
+        rows.iter()
+            .map(|row: &Vec<String>| row[i].len())
+            .max()
+            .unwrap_or(0)
+    };
+    let (first, second) = (width(0), width(1));
+    println!("{}", problem.name());
+    for row in &rows {
+        println!("{:<first$}  {:<second$}  {}", row[0], row[1], row[2]);
+    }
+    Ok(succeeded)
+}
+
+// each profile's inputs have their own answers
+fn answers_path(year: u16, profile: &str) -> Result<PathBuf> {
+    match profile == DEFAULT_PROFILE {
+        true => year_path(year, "answers.toml"),
+        false => year_path(year, &format!("answers.{}.toml", profile)),
+    }
+}
+
+fn solve(problem: &dyn DailyProblem, profile: &str) -> Result<(String, String)> {
+    let input = problem.get_input(&InputSource::cached(profile))?;
+    problem
+        .solutions(&input, &Params::default())
+        .map_err(|e| e.in_day(problem.index()))
+}
+
+// multi-line answers (day 10) are escaped so they fit in a table row
+fn one_line(answer: &str) -> String {
+    match answer.contains('\n') {
+        true => escape(answer),
+        false => answer.to_string(),
+    }
+}
+
+fn report(result: Result<()>) -> bool {
+    match result {
+        Ok(()) => true,
+        Err(e) => {
+            eprintln!("{}", e);
+            false
         }
     }
 }
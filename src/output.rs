@@ -0,0 +1,256 @@
+use std::time::Duration;
+
+use crate::format_duration;
+
+// one answer, with everything a formatter might want to say about it
+#[derive(Debug, Clone, PartialEq)]
+pub struct Record {
+    pub day: u8,
+    pub name: String,
+    pub part: u8,
+    pub answer: String,
+    pub time: Duration,
+}
+
+// turns records into output, a day's records always come together and
+// are followed by end_day; what's returned is printed as is
+pub trait Formatter {
+    fn record(&mut self, record: &Record) -> String;
+
+    fn end_day(&mut self) -> String {
+        String::new()
+    }
+
+    fn finish(&mut self) -> String {
+        String::new()
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Format {
+    Text,
+    Json,
+    Csv,
+}
+
+impl Format {
+    pub fn new(name: &str) -> Option<Self> {
+        match name {
+            "text" => Some(Self::Text),
+            "json" => Some(Self::Json),
+            "csv" => Some(Self::Csv),
+            _ => None,
+        }
+    }
+
+    pub fn formatter(&self) -> Box<dyn Formatter> {
+        match self {
+            Self::Text => Box::<Text>::default(),
+            Self::Json => Box::<Json>::default(),
+            Self::Csv => Box::<Csv>::default(),
+        }
+    }
+}
+
+// the name of each day underlined, then its answers and how long they took
+#[derive(Default)]
+pub struct Text {
+    days_printed: usize,
+    times: Vec<String>,
+}
+
+impl Formatter for Text {
+    fn record(&mut self, record: &Record) -> String {
+        let mut s = String::new();
+        if self.times.is_empty() {
+            if self.days_printed > 0 {
+                s.push('\n');
+            }
+            s.push_str(&format!("{}\n", record.name));
+            s.push_str(&format!("{}\n", "=".repeat(record.name.len())));
+        }
+        self.times.push(format!(
+            "{} (part {})",
+            format_duration(record.time),
+            record.part
+        ));
+        s.push_str(&format!("Part {}: {}\n", record.part, record.answer));
+        s
+    }
+
+    fn end_day(&mut self) -> String {
+        if self.times.is_empty() {
+            return String::new();
+        }
+        self.days_printed += 1;
+        let s = format!("Time: {}\n", self.times.join(", "));
+        self.times.clear();
+        s
+    }
+}
+
+// an array of objects, time is in seconds
+#[derive(Default)]
+pub struct Json {
+    records: usize,
+}
+
+impl Formatter for Json {
+    fn record(&mut self, record: &Record) -> String {
+        let separator = match self.records {
+            0 => "[\n",
+            _ => ",\n",
+        };
+        self.records += 1;
+        format!(
+            "{}  {{\"day\": {}, \"name\": {}, \"part\": {}, \"answer\": {}, \"seconds\": {:.6}}}",
+            separator,
+            record.day,
+            json_string(&record.name),
+            record.part,
+            json_string(&record.answer),
+            record.time.as_secs_f64()
+        )
+    }
+
+    fn finish(&mut self) -> String {
+        match self.records {
+            0 => "[]\n".to_string(),
+            _ => "\n]\n".to_string(),
+        }
+    }
+}
+
+fn json_string(s: &str) -> String {
+    let mut escaped = "\"".to_string();
+    for c in s.chars() {
+        match c {
+            '"' => escaped.push_str("\\\""),
+            '\\' => escaped.push_str("\\\\"),
+            '\n' => escaped.push_str("\\n"),
+            '\r' => escaped.push_str("\\r"),
+            '\t' => escaped.push_str("\\t"),
+            c if c.is_control() => escaped.push_str(&format!("\\u{:04x}", c as u32)),
+            c => escaped.push(c),
+        }
+    }
+    escaped.push('"');
+    escaped
+}
+
+// a header row, then a row per record, time is in seconds
+#[derive(Default)]
+pub struct Csv {
+    header_printed: bool,
+}
+
+impl Formatter for Csv {
+    fn record(&mut self, record: &Record) -> String {
+        let mut s = String::new();
+        if !self.header_printed {
+            s.push_str("day,name,part,answer,seconds\n");
+            self.header_printed = true;
+        }
+        s.push_str(&format!(
+            "{},{},{},{},{:.6}\n",
+            record.day,
+            csv_field(&record.name),
+            record.part,
+            csv_field(&record.answer),
+            record.time.as_secs_f64()
+        ));
+        s
+    }
+}
+
+// quoted only when it has to be, like day 10's multi-line answer
+fn csv_field(s: &str) -> String {
+    match s.contains([',', '"', '\n', '\r']) {
+        true => format!("\"{}\"", s.replace('"', "\"\"")),
+        false => s.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::{Format, Record};
+
+    fn records() -> Vec<Record> {
+        let record = |day, name: &str, part, answer: &str, micros| Record {
+            day,
+            name: name.to_string(),
+            part,
+            answer: answer.to_string(),
+            time: Duration::from_micros(micros),
+        };
+        vec![
+            record(2, "Day 2: Rock Paper Scissors", 1, "15", 12),
+            record(2, "Day 2: Rock Paper Scissors", 2, "12", 8),
+            record(10, "Day 10: Cathode-Ray Tube", 2, "\n#.\n\"#", 1500),
+        ]
+    }
+
+    fn format(format: Format) -> String {
+        let mut formatter = format.formatter();
+        let mut output = String::new();
+        let records = records();
+        for (i, record) in records.iter().enumerate() {
+            output.push_str(&formatter.record(record));
+            if records.get(i + 1).map(|r| r.day) != Some(record.day) {
+                output.push_str(&formatter.end_day());
+            }
+        }
+        output.push_str(&formatter.finish());
+        output
+    }
+
+    #[test]
+    fn text() {
+        assert_eq!(
+            format(Format::Text),
+            "Day 2: Rock Paper Scissors
+==========================
+Part 1: 15
+Part 2: 12
+Time: 12µs (part 1), 8µs (part 2)
+
+Day 10: Cathode-Ray Tube
+========================
+Part 2: 
+#.
+\"#
+Time: 1.50ms (part 2)
+"
+        );
+    }
+
+    #[test]
+    fn json() {
+        assert_eq!(
+            format(Format::Json),
+            r##"[
+  {"day": 2, "name": "Day 2: Rock Paper Scissors", "part": 1, "answer": "15", "seconds": 0.000012},
+  {"day": 2, "name": "Day 2: Rock Paper Scissors", "part": 2, "answer": "12", "seconds": 0.000008},
+  {"day": 10, "name": "Day 10: Cathode-Ray Tube", "part": 2, "answer": "\n#.\n\"#", "seconds": 0.001500}
+]
+"##
+        );
+        assert_eq!(Format::Json.formatter().finish(), "[]\n");
+    }
+
+    #[test]
+    fn csv() {
+        assert_eq!(
+            format(Format::Csv),
+            "day,name,part,answer,seconds
+2,Day 2: Rock Paper Scissors,1,15,0.000012
+2,Day 2: Rock Paper Scissors,2,12,0.000008
+10,Day 10: Cathode-Ray Tube,2,\"
+#.
+\"\"#\",0.001500
+"
+        );
+    }
+}
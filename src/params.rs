@@ -0,0 +1,77 @@
+use std::collections::BTreeMap;
+
+use crate::{Error, Result};
+
+// a number baked into a puzzle, like the row day 15 looks at, that's
+// different for the examples than for the real input
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Param {
+    pub name: &'static str,
+    pub default: u64,
+    pub description: &'static str,
+}
+
+// values given on the command line, anything not in here uses its default
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct Params(BTreeMap<String, u64>);
+
+impl Params {
+    pub fn set(&mut self, name: &str, value: u64) {
+        self.0.insert(name.to_string(), value);
+    }
+
+    pub fn get(&self, param: &Param) -> u64 {
+        self.0.get(param.name).copied().unwrap_or(param.default)
+    }
+
+    // for puzzles that want something smaller than a u64
+    pub fn get_as<T: TryFrom<u64>>(&self, param: &Param) -> Result<T> {
+        let value = self.get(param);
+        T::try_from(value)
+            .map_err(|_| Error::no_solution(&format!("{} = {} is too big", param.name, value)))
+    }
+
+    pub fn names(&self) -> impl Iterator<Item = &str> {
+        self.0.keys().map(|name| name.as_str())
+    }
+
+    // "row=10"
+    pub fn parse_arg(arg: &str) -> Option<(String, u64)> {
+        let (name, value) = arg.split_once('=')?;
+        match name.trim() {
+            "" => None,
+            name => Some((name.to_string(), value.trim().parse().ok()?)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Param, Params};
+
+    const ROW: Param = Param {
+        name: "row",
+        default: 2000000,
+        description: "the row to check",
+    };
+
+    #[test]
+    fn get() {
+        let mut params = Params::default();
+        assert_eq!(params.get(&ROW), 2000000);
+        params.set("row", 10);
+        assert_eq!(params.get(&ROW), 10);
+        assert_eq!(params.names().collect::<Vec<_>>(), vec!["row"]);
+        assert_eq!(params.get_as::<i32>(&ROW), Ok(10));
+        params.set("row", u64::MAX);
+        assert!(params.get_as::<i32>(&ROW).is_err());
+    }
+
+    #[test]
+    fn parse_arg() {
+        assert_eq!(Params::parse_arg("row=10"), Some(("row".to_string(), 10)));
+        assert_eq!(Params::parse_arg("row"), None);
+        assert_eq!(Params::parse_arg("=10"), None);
+        assert_eq!(Params::parse_arg("row=-1"), None);
+    }
+}
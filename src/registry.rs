@@ -0,0 +1,160 @@
+use std::{collections::BTreeMap, sync::Arc};
+
+use crate::DailyProblem;
+
+// advent of code has a puzzle on each of these days
+pub const DAYS: std::ops::RangeInclusive<u8> = 1..=25;
+
+// every solved day, looked up by its year and real day number rather
+// than by where it happens to sit in a list. days are shared so one can be
+// handed to a thread of its own, see DailyProblem::run_parts
+#[derive(Default)]
+pub struct Registry(BTreeMap<(u16, u8), Arc<dyn DailyProblem>>);
+
+impl Registry {
+    // two problems claiming the same day is a mistake in the code, not
+    // something to recover from
+    pub fn new(problems: Vec<Box<dyn DailyProblem>>) -> Self {
+        let mut registry = Self::default();
+        for problem in problems {
+            let key = (problem.year(), problem.index());
+            if let Some(existing) = registry.0.get(&key) {
+                panic!(
+                    "day {} of {} is registered twice, by \"{}\" and \"{}\"",
+                    key.1,
+                    key.0,
+                    existing.name(),
+                    problem.name()
+                );
+            }
+            registry.0.insert(key, problem.into());
+        }
+        registry
+    }
+
+    pub fn get(&self, year: u16, day: u8) -> Option<&dyn DailyProblem> {
+        self.0.get(&(year, day)).map(|p| p.as_ref())
+    }
+
+    // the same, for running on another thread that may outlive the caller
+    pub fn shared(&self, year: u16, day: u8) -> Option<Arc<dyn DailyProblem>> {
+        self.0.get(&(year, day)).cloned()
+    }
+
+    // one year's days in day order, however they were registered
+    pub fn iter(&self, year: u16) -> impl Iterator<Item = &dyn DailyProblem> {
+        self.0
+            .range((year, 0)..=(year, u8::MAX))
+            .map(|(_, p)| p.as_ref())
+    }
+
+    pub fn days(&self, year: u16) -> Vec<u8> {
+        self.iter(year).map(|p| p.index()).collect()
+    }
+
+    // every year with at least one solved day, oldest first
+    pub fn years(&self) -> Vec<u16> {
+        let mut years: Vec<u16> = self.0.keys().map(|(year, _)| *year).collect();
+        years.dedup();
+        years
+    }
+
+    // days of the year's calendar without a solution yet
+    pub fn missing(&self, year: u16) -> Vec<u8> {
+        DAYS.filter(|day| !self.0.contains_key(&(year, *day)))
+            .collect()
+    }
+}
+
+// declares each day's module and builds a Registry out of them, so adding
+// a day is one line:
+//
+// register_days! {
+//     calorie_counting::CalorieCounting,
+//     rock_paper_scissors::RockPaperScissors,
+// }
+//
+// which gives `pub mod calorie_counting; pub mod rock_paper_scissors;`
+// and `pub fn registry() -> Registry`, plus a test per day checking its
+// example, named after the module, e.g. `examples::calorie_counting`
+#[macro_export]
+macro_rules! register_days {
+    ($($module:ident :: $problem:ident),* $(,)?) => {
+        $(pub mod $module;)*
+
+        pub fn registry() -> $crate::Registry {
+            $crate::Registry::new(vec![$(Box::new($module::$problem {}),)*])
+        }
+
+        #[cfg(test)]
+        mod examples {
+            $(
+                #[test]
+                fn $module() {
+                    $crate::check_example(&super::$module::$problem {});
+                }
+            )*
+        }
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Registry;
+    use crate::{DailyProblem, Params, Result};
+
+    struct Day(u16, u8, &'static str);
+
+    impl DailyProblem for Day {
+        fn name(&self) -> &str {
+            self.2
+        }
+        fn index(&self) -> u8 {
+            self.1
+        }
+        fn year(&self) -> u16 {
+            self.0
+        }
+        fn part_one(&self, _input: &str, _params: &Params) -> Result<String> {
+            Ok(String::new())
+        }
+        fn part_two(&self, _input: &str, _params: &Params) -> Result<String> {
+            Ok(String::new())
+        }
+    }
+
+    #[test]
+    fn lookup() {
+        let registry = Registry::new(vec![
+            Box::new(Day(2022, 14, "fourteen")),
+            Box::new(Day(2022, 2, "two")),
+            Box::new(Day(2022, 1, "one")),
+            Box::new(Day(2021, 2, "last year's two")),
+        ]);
+        assert_eq!(registry.years(), vec![2021, 2022]);
+        assert_eq!(registry.days(2022), vec![1, 2, 14]);
+        assert_eq!(registry.days(2021), vec![2]);
+        assert_eq!(registry.get(2022, 14).map(|p| p.name()), Some("fourteen"));
+        assert!(registry.get(2022, 3).is_none());
+        assert_eq!(
+            registry.get(2021, 2).map(|p| p.name()),
+            Some("last year's two")
+        );
+        assert_eq!(
+            registry.iter(2022).map(|p| p.name()).collect::<Vec<_>>(),
+            vec!["one", "two", "fourteen"]
+        );
+        assert_eq!(registry.missing(2022).len(), 22);
+        assert_eq!(registry.missing(2022)[..3], [3, 4, 5]);
+        assert_eq!(registry.missing(2021)[..3], [1, 3, 4]);
+    }
+
+    #[test]
+    #[should_panic(expected = "day 2 of 2022 is registered twice")]
+    fn duplicate_day() {
+        Registry::new(vec![
+            Box::new(Day(2022, 2, "two")),
+            Box::new(Day(2022, 2, "also two")),
+        ]);
+    }
+}
@@ -0,0 +1,255 @@
+use std::io::{BufRead, Write};
+
+use crate::{DailyProblem, Error, Params, Result};
+
+// a day's puzzle state that can be moved forward a step at a time, so it
+// can be watched from the repl
+pub trait Simulation {
+    // moves on by one step, true once there's nothing left to do
+    fn step(&mut self) -> Result<bool>;
+
+    // the current state drawn out, usually as a grid
+    fn render(&self) -> String;
+
+    // the numbers worth keeping an eye on, by name
+    fn counters(&self) -> Vec<(String, String)>;
+}
+
+const HELP: &str = "Commands:
+  step [N], s [N]   move on N steps (default 1)
+  print, p          draw the current state
+  counters, c       show the counters
+  reset, r          start again from the input
+  help, h           show this
+  quit, q           leave
+";
+
+// reads commands from `commands` until it runs out or is told to quit,
+// writing everything to `out`
+pub fn repl(
+    problem: &dyn DailyProblem,
+    input: &str,
+    part: u8,
+    params: &Params,
+    commands: impl BufRead,
+    mut out: impl Write,
+) -> Result<()> {
+    let start = || match problem.simulation(input, part, params) {
+        Some(simulation) => simulation.map_err(|e| e.in_part(part).in_day(problem.index())),
+        None => Err(
+            Error::no_solution("there's nothing to step through for this day")
+                .in_day(problem.index()),
+        ),
+    };
+    let write_error = |e| Error::input(&format!("could not write to the terminal: {}", e));
+
+    let mut session = Session::new(start()?);
+    writeln!(out, "{}, part {}", problem.name(), part).map_err(write_error)?;
+    write!(out, "{}", HELP).map_err(write_error)?;
+    prompt(&mut out).map_err(write_error)?;
+    for line in commands.lines() {
+        let line = line.map_err(|e| Error::input(&format!("could not read a command: {}", e)))?;
+        let words: Vec<&str> = line.split_whitespace().collect();
+        let reply = match words.as_slice() {
+            [] => String::new(),
+            ["step" | "s"] => session.step(1),
+            ["step" | "s", n] => match n.parse() {
+                Ok(n) => session.step(n),
+                Err(_) => format!("\"{}\" is not a number of steps\n", n),
+            },
+            ["print" | "p"] => session.simulation.render(),
+            ["counters" | "c"] => session.counters(),
+            ["reset" | "r"] => {
+                session = Session::new(start()?);
+                "back to the start\n".to_string()
+            }
+            ["help" | "h"] => HELP.to_string(),
+            ["quit" | "q"] => break,
+            _ => format!("unknown command \"{}\", try help\n", line.trim()),
+        };
+        write!(out, "{}", reply).map_err(write_error)?;
+        prompt(&mut out).map_err(write_error)?;
+    }
+    writeln!(out).map_err(write_error)
+}
+
+// a simulation along with how far it's got
+struct Session<'a> {
+    simulation: Box<dyn Simulation + 'a>,
+    steps: usize,
+    finished: bool,
+    failed: bool,
+}
+
+impl<'a> Session<'a> {
+    fn new(simulation: Box<dyn Simulation + 'a>) -> Self {
+        Self {
+            simulation,
+            steps: 0,
+            finished: false,
+            failed: false,
+        }
+    }
+
+    // stops early if the simulation finishes or fails, and once it's failed
+    // doesn't step it again until it's reset
+    fn step(&mut self, n: usize) -> String {
+        for _ in 0..n {
+            if self.finished || self.failed {
+                break;
+            }
+            self.steps += 1;
+            match self.simulation.step() {
+                Ok(finished) => self.finished = finished,
+                Err(e) => {
+                    self.failed = true;
+                    return format!("step {}: {}\n", self.steps, e);
+                }
+            }
+        }
+        match (self.finished, self.failed) {
+            (_, true) => format!(
+                "step {}, stopped by an error, reset to start again\n",
+                self.steps
+            ),
+            (true, _) => format!("step {}, finished\n", self.steps),
+            _ => format!("step {}\n", self.steps),
+        }
+    }
+
+    fn counters(&self) -> String {
+        let mut counters = vec![("steps".to_string(), self.steps.to_string())];
+        counters.extend(self.simulation.counters());
+        let width = counters
+            .iter()
+            .map(|(name, _)| name.len())
+            .max()
+            .unwrap_or(0);
+        counters
+            .iter()
+            .map(|(name, value)| format!("{:<width$}  {}\n", name, value))
+            .collect()
+    }
+}
+
+fn prompt(out: &mut impl Write) -> std::io::Result<()> {
+    write!(out, "> ")?;
+    out.flush()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{repl, Simulation};
+    use crate::{DailyProblem, Error, Params, Result};
+
+    // counts up to a limit
+    struct Counter(u32, u32);
+
+    impl Simulation for Counter {
+        fn step(&mut self) -> Result<bool> {
+            self.0 += 1;
+            Ok(self.0 == self.1)
+        }
+        fn render(&self) -> String {
+            format!("{}\n", "#".repeat(self.0 as usize))
+        }
+        fn counters(&self) -> Vec<(String, String)> {
+            vec![("count".to_string(), self.0.to_string())]
+        }
+    }
+
+    // fails on its second step
+    struct Broken(u32);
+
+    impl Simulation for Broken {
+        fn step(&mut self) -> Result<bool> {
+            self.0 += 1;
+            match self.0 {
+                1 => Ok(false),
+                _ => Err(Error::no_solution("broken")),
+            }
+        }
+        fn render(&self) -> String {
+            String::new()
+        }
+        fn counters(&self) -> Vec<(String, String)> {
+            vec![]
+        }
+    }
+
+    struct Day;
+
+    impl DailyProblem for Day {
+        fn name(&self) -> &str {
+            "Day 0: Counting"
+        }
+        fn index(&self) -> u8 {
+            0
+        }
+        fn part_one(&self, _input: &str, _params: &Params) -> Result<String> {
+            Ok(String::new())
+        }
+        fn part_two(&self, _input: &str, _params: &Params) -> Result<String> {
+            Ok(String::new())
+        }
+        fn simulation<'a>(
+            &self,
+            input: &'a str,
+            _part: u8,
+            _params: &Params,
+        ) -> Option<Result<Box<dyn Simulation + 'a>>> {
+            match input {
+                "broken" => Some(Ok(Box::new(Broken(0)))),
+                _ => Some(Ok(Box::new(Counter(0, input.trim().parse().unwrap())))),
+            }
+        }
+    }
+
+    fn run(commands: &str) -> String {
+        run_on("5", commands)
+    }
+
+    fn run_on(input: &str, commands: &str) -> String {
+        let mut out = vec![];
+        repl(
+            &Day,
+            input,
+            1,
+            &Params::default(),
+            commands.as_bytes(),
+            &mut out,
+        )
+        .unwrap();
+        let out = String::from_utf8(out).unwrap();
+        // everything after the help text
+        out.split_once("leave\n").unwrap().1.to_string()
+    }
+
+    #[test]
+    fn commands() {
+        assert_eq!(
+            run("step\ns 2\np\nc\n"),
+            "> step 1\n> step 3\n> ###\n> steps  3\ncount  3\n> \n"
+        );
+        assert_eq!(
+            run("step 10\nstep\nreset\nprint\n"),
+            "> step 5, finished\n> step 5, finished\n> back to the start\n> \n> \n"
+        );
+        assert_eq!(run("s 2\nq\nstep\n"), "> step 2\n> \n");
+        assert_eq!(
+            run("step two\njump\n"),
+            "> \"two\" is not a number of steps\n> unknown command \"jump\", try help\n> \n"
+        );
+    }
+
+    #[test]
+    fn error() {
+        assert_eq!(
+            run_on("broken", "step 5\nstep 5\nreset\nstep\n"),
+            "> step 2: no solution: broken\n\
+             > step 2, stopped by an error, reset to start again\n\
+             > back to the start\n\
+             > step 1\n> \n"
+        );
+    }
+}